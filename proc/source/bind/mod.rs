@@ -0,0 +1,398 @@
+//! The spanned `bind!` macro
+
+
+#[cfg(test)]
+mod test;
+
+
+use proc_macro2::{Delimiter, Span, TokenStream};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{
+    Attribute, Expr, Ident, Token, Type, parenthesized,
+    parse::{Parse, ParseStream},
+    parse2,
+    spanned::Spanned,
+    token::Paren,
+};
+
+
+mod kw {
+    syn::custom_keyword!(assert);
+    syn::custom_keyword!(capture);
+    syn::custom_keyword!(err);
+    syn::custom_keyword!(keep);
+    syn::custom_keyword!(none);
+    syn::custom_keyword!(or);
+    syn::custom_keyword!(or_else);
+    syn::custom_keyword!(throw);
+}
+
+
+/// Parsed `bind!` invocation.
+pub struct Bind {
+    attrs: Vec<Attribute>,
+    mutability: Option<Token![mut]>,
+    name: Name,
+    keep: Option<kw::keep>,
+    value: Option<Expr>,
+    error_type: Option<Type>,
+    recovery: Option<Expr>,
+    /// The `or` clause, omitted if the error is `Infallible`.
+    pub or: Option<Or>,
+}
+
+/// Variable name of a `bind!` invocation.
+enum Name {
+    Ident(Ident),
+    /// Only tests the value, keeping it in an inaccessible variable.
+    Underscore(Token![_]),
+}
+
+impl Parse for Name {
+
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![_]) {
+            Ok(Self::Underscore(input.parse()?))
+        } else {
+            Ok(Self::Ident(input.parse()?))
+        }
+    }
+
+}
+
+impl ToTokens for Name {
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Ident(ident) => ident.to_tokens(tokens),
+            Self::Underscore(underscore) => underscore.to_tokens(tokens),
+        }
+    }
+
+}
+
+/// The `or` clause of a `bind!` invocation.
+pub struct Or {
+    pub handler: Option<Handler>,
+    pub flow: Flow,
+    assertion: Option<Expr>,
+}
+
+/// Error handler of a `bind!` invocation.
+pub enum Handler {
+    Expr(Expr),
+    /// Passes the error to the `ErrorSink`.
+    Capture(kw::capture, Expr),
+}
+
+impl ToTokens for Handler {
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Expr(expr) => expr.to_tokens(tokens),
+            Self::Capture(capture, sink) => {
+                capture.to_tokens(tokens);
+                sink.to_tokens(tokens);
+            },
+        }
+    }
+
+}
+
+/// Execution flow control expression of a `bind!` invocation.
+pub enum Flow {
+    Expr(Expr),
+    /// Returns `None`.
+    None(kw::none),
+    /// Returns the provided error converted with `Into`.
+    Err(kw::err, Paren, Expr),
+    /// Returns the error converted with `Into`.
+    Throw(kw::throw),
+    /// Yields the value and tests the value expression again once resumed.
+    Yield(Token![yield], Expr),
+}
+
+impl Flow {
+
+    /// Tests whether the input starts with `err(<error>)` rather than an expression
+    /// calling a function named `err`, such as the error handler followed by the flow.
+    fn peek_err(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.parse::<kw::err>().is_ok()
+            && fork.step(|cursor| match cursor.group(Delimiter::Parenthesis) {
+                Some((_, _, rest)) => Ok(((), rest)),
+                None => Err(cursor.error("expected parentheses")),
+            }).is_ok()
+            && (fork.is_empty() || (fork.peek(Token![,]) && fork.peek2(kw::assert)))
+    }
+
+}
+
+impl Parse for Flow {
+
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::throw) {
+            Ok(Self::Throw(input.parse()?))
+        } else if input.peek(kw::none) {
+            Ok(Self::None(input.parse()?))
+        } else if input.peek(Token![yield]) {
+            Ok(Self::Yield(input.parse()?, input.parse()?))
+        } else if Self::peek_err(input) {
+            let content;
+            Ok(Self::Err(input.parse()?, parenthesized!(content in input), content.parse()?))
+        } else {
+            Ok(Self::Expr(input.parse()?))
+        }
+    }
+
+}
+
+impl ToTokens for Flow {
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Expr(expr) => expr.to_tokens(tokens),
+            Self::None(none) => none.to_tokens(tokens),
+            Self::Err(err, paren, error) => {
+                err.to_tokens(tokens);
+                paren.surround(tokens, |tokens| error.to_tokens(tokens));
+            },
+            Self::Throw(throw) => throw.to_tokens(tokens),
+            Self::Yield(yield_token, value) => {
+                yield_token.to_tokens(tokens);
+                value.to_tokens(tokens);
+            },
+        }
+    }
+
+}
+
+impl Parse for Or {
+
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (handler, flow) = if input.peek(kw::capture) && !input.peek2(Token![,]) {
+            let capture = input.parse::<kw::capture>()?;
+            let sink = input.parse()?;
+            input.parse::<Token![,]>()?;
+            let flow = input.parse()?;
+            if let Flow::Throw(_) = flow {
+                return Err(syn::Error::new(capture.span, "`capture` isn't supported with `throw`"));
+            }
+            (Some(Handler::Capture(capture, sink)), flow)
+        } else {
+            match input.parse()? {
+                Flow::Expr(first) if input.peek(Token![,]) && !input.peek2(kw::assert) => {
+                    input.parse::<Token![,]>()?;
+                    (Some(Handler::Expr(first)), input.parse()?)
+                },
+                flow => (None, flow),
+            }
+        };
+        let assertion = match input.parse::<Option<Token![,]>>()? {
+            Some(_) => {
+                input.parse::<kw::assert>()?;
+                Some(input.parse()?)
+            },
+            None => None,
+        };
+        Ok(Self { handler, flow, assertion })
+    }
+
+}
+
+impl ToTokens for Or {
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { handler, flow, assertion } = self;
+        let handler = handler.as_ref().map(|handler| quote! { #handler, });
+        let assertion = assertion.as_ref().map(|assertion| quote! { , assert #assertion });
+        tokens.extend(quote! { #handler #flow #assertion });
+    }
+
+}
+
+impl Parse for Bind {
+
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let mutability = input.parse()?;
+        let name = input.parse()?;
+        let (keep, value) = match input.parse::<Option<Token![=]>>()? {
+            Some(_) if input.peek(kw::keep) && !input.peek2(Token![,]) => {
+                (Some(input.parse()?), Some(input.parse()?))
+            },
+            Some(_) => (None, Some(input.parse()?)),
+            None => (None, None),
+        };
+        let error_type = if input.peek(Token![,]) && input.peek2(Token![as]) {
+            input.parse::<Token![,]>()?;
+            input.parse::<Token![as]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let recovery = if input.peek(Token![,]) && input.peek2(kw::or_else) {
+            input.parse::<Token![,]>()?;
+            let or_else = input.parse::<kw::or_else>()?;
+            if keep.is_some() {
+                return Err(syn::Error::new(or_else.span, "`or_else` isn't supported with `keep`"));
+            }
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let or = if input.is_empty() {
+            None
+        } else {
+            input.parse::<Token![,]>()?;
+            input.parse::<kw::or>()?;
+            Some(input.parse()?)
+        };
+        if let (Name::Underscore(underscore), None) = (&name, &value) {
+            return Err(syn::Error::new(underscore.span, "expected `=` after `_`"));
+        }
+        Ok(Self { attrs, mutability, name, keep, value, error_type, recovery, or })
+    }
+
+}
+
+impl ToTokens for Bind {
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { attrs, mutability, name, keep, value, error_type, recovery, or } = self;
+        let value = value.as_ref().map(|value| quote! { = #keep #value });
+        let error_type = error_type.as_ref().map(|error_type| quote! { , as #error_type });
+        let recovery = recovery.as_ref().map(|recovery| quote! { , or_else #recovery });
+        let or = or.as_ref().map(|or| quote! { , or #or });
+        tokens.extend(quote! { #(#attrs)* #mutability #name #value #error_type #recovery #or });
+    }
+
+}
+
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let Bind { attrs, mutability, name, keep, value, error_type, recovery, or } = parse2(input)?;
+
+    let name = match name {
+        Name::Ident(ident) => ident,
+        Name::Underscore(underscore) => {
+            Ident::new("_value", Span::mixed_site().located_at(underscore.span))
+        },
+    };
+
+    let value_ident = Ident::new("value", Span::mixed_site());
+    let err = Ident::new("err", Span::mixed_site());
+    let result = Ident::new("result", Span::mixed_site());
+    let never = Ident::new("never", Span::mixed_site());
+
+    let source = match (&keep, &value) {
+        (Some(_), Some(value)) => quote_spanned! { value.span()=>
+            use ::el_macro::bind::TryUnwrap;
+            (#value).try_unwrap()
+        },
+        (None, Some(value)) => quote_spanned! { value.span()=>
+            use ::el_macro::bind::IntoResult;
+            (#value).into_result()
+        },
+        (_, None) => quote_spanned! { name.span()=>
+            use ::el_macro::bind::IntoResult;
+            #name.into_result()
+        },
+    };
+    let source = match recovery {
+        Some(recovery) => quote_spanned! { recovery.span()=>
+            match { #source } {
+                ::core::result::Result::Ok(#value_ident) => ::core::result::Result::Ok(#value_ident),
+                ::core::result::Result::Err(#err) => {
+                    use ::el_macro::bind::IntoResult;
+                    ::el_macro::private::call_with(#recovery, #err).into_result()
+                },
+            }
+        },
+        None => quote! { { #source } },
+    };
+    let source = match error_type {
+        Some(error_type) => quote_spanned! { error_type.span()=>
+            ::core::convert::identity::<::core::result::Result<_, #error_type>>(#source)
+        },
+        None => source,
+    };
+    let source = quote! { ::el_macro::__bind_stats!(#name, #source) };
+
+    let Some(Or { handler, flow, assertion }) = or else {
+        return Ok(quote! {
+            #(#attrs)* let #mutability #name = match #source {
+                ::core::result::Result::Ok(#value_ident) => #value_ident,
+                ::core::result::Result::Err(#err) => {
+                    let #never: ::core::convert::Infallible = #err;
+                    match #never { }
+                },
+            };
+        });
+    };
+
+    // the error is thrown after the handler is called, so the handler gets a reference to it
+    let handled_err = match flow {
+        Flow::Throw(_) => quote! { &#err },
+        _ => quote! { #err },
+    };
+    let handle = handler.map(|handler| match handler {
+        Handler::Expr(handler) => quote_spanned! { handler.span()=>
+            ::el_macro::private::handle(#handler, #handled_err);
+        },
+        Handler::Capture(_, sink) => quote_spanned! { sink.span()=>
+            {
+                use ::el_macro::bind::ErrorSink;
+                (#sink).capture(#err);
+            }
+        },
+    });
+
+    let assertion = assertion.map(|assertion| quote_spanned! { assertion.span()=>
+        ::core::debug_assert!(
+            #assertion,
+            "`{}` doesn't hold for `{}` = {:?}",
+            ::core::stringify!(#assertion), ::core::stringify!(#name), #name,
+        );
+    });
+
+    let flow = match flow {
+        Flow::Expr(flow) => flow.into_token_stream(),
+        Flow::None(none) => quote_spanned! { none.span()=>
+            return ::core::option::Option::None
+        },
+        Flow::Err(_, _, error) => quote_spanned! { error.span()=>
+            return ::core::result::Result::Err(::core::convert::Into::into(#error))
+        },
+        Flow::Throw(throw) => quote_spanned! { throw.span()=>
+            return ::core::result::Result::Err(::core::convert::Into::into(#err))
+        },
+        Flow::Yield(_, value) => return Ok(quote! {
+            // the temporaries of the value expression are dropped before yielding
+            #(#attrs)* let #mutability #name = loop {
+                let #result = #source;
+                match #result {
+                    ::core::result::Result::Ok(#value_ident) => break #value_ident,
+                    #[allow(unused_variables)]
+                    ::core::result::Result::Err(#err) => {
+                        #handle
+                        yield #value;
+                    },
+                }
+            };
+            #assertion
+        }),
+    };
+
+    Ok(quote! {
+        #(#attrs)* let #mutability #name = match #source {
+            ::core::result::Result::Ok(#value_ident) => #value_ident,
+            #[allow(unused_variables)]
+            ::core::result::Result::Err(#err) => {
+                #handle
+                #flow
+            },
+        };
+        #assertion
+    })
+}
@@ -0,0 +1,93 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::parse2;
+
+use super::{Bind, Flow, Handler, expand};
+
+
+fn parse(tokens: TokenStream) -> Bind {
+    parse2(tokens).unwrap()
+}
+
+
+#[test]
+fn every_clause_round_trips() {
+
+    for tokens in [
+        quote! { #[allow(unused_variables)] mut x = keep y, or |_| { }, continue },
+        quote! { _ = ready(), or err(Error::Busy) },
+        quote! { x, as ParseIntError, or throw, assert x > 0 },
+        quote! { x = primary(), or_else |err| fallback(err), or capture errors, return None },
+        quote! { x = poll(), or yield 1 },
+        quote! { x = infallible() },
+    ] {
+        assert_eq!(parse(tokens.clone()).into_token_stream().to_string(), tokens.to_string());
+    }
+
+}
+
+
+#[test]
+fn flow_keywords() {
+
+    let flow = |tokens| parse(tokens).or.unwrap().flow;
+    assert!(matches!(flow(quote! { x, or none }), Flow::None(_)));
+    assert!(matches!(flow(quote! { x, or err(Error::Busy) }), Flow::Err(..)));
+    assert!(matches!(flow(quote! { x, or err(Error::Busy), assert x > 0 }), Flow::Err(..)));
+    assert!(matches!(flow(quote! { x, or throw, assert x > 0 }), Flow::Throw(_)));
+    assert!(matches!(flow(quote! { x, or yield 1 }), Flow::Yield(..)));
+    // calls of functions named `err` are left as is
+    assert!(matches!(flow(quote! { x, or err(Error::Busy).into() }), Flow::Expr(_)));
+
+}
+
+
+#[test]
+fn handlers() {
+
+    let handler = |tokens| parse(tokens).or.unwrap().handler;
+    assert!(handler(quote! { x, or return, assert x > 0 }).is_none());
+    assert!(matches!(handler(quote! { x, or log, return }), Some(Handler::Expr(_))));
+    assert!(matches!(handler(quote! { x, or capture sink, continue }), Some(Handler::Capture(..))));
+    // handlers named `capture` are not sinks
+    assert!(matches!(handler(quote! { x, or capture, continue }), Some(Handler::Expr(_))));
+    // handlers named `err` are not the flow
+    assert!(matches!(handler(quote! { x, or err(ctx), return }), Some(Handler::Expr(_))));
+
+}
+
+
+#[test]
+fn unsupported_combinations() {
+
+    for tokens in [
+        quote! { _, or return },
+        quote! { x = keep y, or_else recover, or return },
+        quote! { x, or capture sink, throw },
+        quote! { x = y, and return },
+    ] {
+        assert!(parse2::<Bind>(tokens).is_err());
+    }
+
+}
+
+
+#[test]
+fn attributes_apply_to_the_let_statement() {
+
+    let expansion = expand(quote! { #[allow(unused_mut)] mut x = y, or return }).unwrap();
+    let attributed_let = quote! { #[allow(unused_mut)] let mut x = };
+    assert!(expansion.to_string().starts_with(&attributed_let.to_string()));
+
+}
+
+
+#[test]
+fn assertion_follows_the_yield_loop() {
+
+    let expansion = expand(quote! { x = poll(), or yield 1, assert x > 0 }).unwrap().to_string();
+    let yielded = expansion.find("yield 1").unwrap();
+    let asserted = expansion.find("debug_assert").unwrap();
+    assert!(yielded < asserted);
+
+}
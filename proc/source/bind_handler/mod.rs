@@ -0,0 +1,48 @@
+//! The `#[bind_handler]` attribute
+
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    Expr, Item, Macro, parse2,
+    visit_mut::{self, VisitMut},
+};
+
+use crate::bind::{Bind, Flow, Handler};
+
+
+/// Inserts the default handler into the `bind!` invocations that don't have one.
+///
+/// The invocations with the `throw` flow control expression are skipped, since their handler
+/// gets a reference to the error rather than the error itself.
+struct Rewriter {
+    handler: Expr,
+}
+
+impl VisitMut for Rewriter {
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        visit_mut::visit_macro_mut(self, mac);
+        if !mac.path.segments.last().is_some_and(|segment| segment.ident == "bind") {
+            return;
+        }
+        // malformed invocations are left for the macro itself to report
+        if let Ok(mut bind) = mac.parse_body::<Bind>() {
+            if let Some(or) = &mut bind.or {
+                if or.handler.is_none() && !matches!(or.flow, Flow::Throw(_)) {
+                    or.handler = Some(Handler::Expr(self.handler.clone()));
+                    mac.tokens = bind.into_token_stream();
+                }
+            }
+        }
+    }
+
+}
+
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let handler = parse2(attr)?;
+    let mut item: Item = parse2(item)?;
+    Rewriter { handler }.visit_item_mut(&mut item);
+    Ok(item.into_token_stream())
+}
@@ -0,0 +1,63 @@
+//! The multi-arm `if_matches!` macro
+
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    Arm, Expr, Token, braced,
+    parse::{Parse, ParseStream},
+    parse2,
+    spanned::Spanned,
+};
+
+
+/// Parsed `if_matches!` invocation.
+struct IfMatches {
+    value: Expr,
+    arms: Vec<Arm>,
+}
+
+impl Parse for IfMatches {
+
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let value = Expr::parse_without_eager_brace(input)?;
+        let mut arms = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            arms.push(input.parse()?);
+        } else {
+            let content;
+            braced!(content in input);
+            while !content.is_empty() {
+                arms.push(content.parse()?);
+            }
+            if arms.is_empty() {
+                return Err(content.error("expected at least one arm"));
+            }
+        }
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the arms"));
+        }
+        Ok(Self { value, arms })
+    }
+
+}
+
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let IfMatches { value, arms } = parse2(input)?;
+
+    let arms = arms.into_iter().map(|Arm { attrs, pat, guard, body, .. }| {
+        let guard = guard.map(|(if_token, guard)| quote! { #if_token #guard });
+        let body = quote_spanned! { body.span()=>
+            ::core::option::Option::Some(::el_macro::private::call(|| #body))
+        };
+        quote! { #(#attrs)* #pat #guard => #body, }
+    });
+
+    Ok(quote! {
+        match #value {
+            #(#arms)*
+            _ => ::core::option::Option::None,
+        }
+    })
+}
@@ -0,0 +1,272 @@
+//! Procedural macros of the [el-macro](https://docs.rs/el-macro) crate
+//!
+//! Not intended to be used directly. Enable the `proc` feature of the `el-macro` crate instead.
+
+
+mod bind;
+mod bind_handler;
+mod if_matches;
+mod or_return;
+
+
+use proc_macro::TokenStream;
+
+
+/// Binds the unwrapped value, pointing the diagnostics at the invocation
+///
+/// Procedural implementation of the `el_macro::bind!` macro. Behaves the same
+/// and supports the same syntax, but preserves the spans of the variable name,
+/// the value expression, the error handler and the flow control expression, so
+/// type errors point at the respective parts of the invocation rather than
+/// at the macro definition.
+///
+/// # Examples
+///
+/// ```
+/// use el_macro::proc::bind;
+///
+/// bind!(x = Some(42), or return, assert x > 0);
+/// assert_eq!(x, 42);
+///
+/// bind!(mut x = Ok::<_, &str>(42), or |err| eprintln!("{err}"), return);
+/// x += 3;
+/// assert_eq!(x, 45);
+///
+/// fn parse_pair(s: &str) -> Option<(i32, i32)> {
+///     bind!(pair = s.split_once(','), or none);
+///     let (a, b) = pair;
+///     bind!(a = a.parse::<i32>(), or none);
+///     bind!(b = b.parse::<i32>(), or |_| eprintln!("{b} isn't a number"), none);
+///     Some((a, b))
+/// }
+///
+/// fn parse_positive(s: &str) -> Result<i32, Box<dyn std::error::Error>> {
+///     bind!(x = s.parse(), as std::num::ParseIntError, or throw);
+///     let x: i32 = x;
+///     bind!(_ = x > 0, or err(format!("{x} isn't positive")));
+///     Ok(x)
+/// }
+///
+/// let parse_port = |s: &str| -> u16 {
+///     bind!(port = s.parse(), or_else |_| s.trim_start_matches(':').parse(), or return 80);
+///     port
+/// };
+///
+/// let mut errors = Vec::new();
+/// let mut sum = 0;
+/// for s in ["40", "x", "2"] {
+///     bind!(x = s.parse::<i32>(), or capture &mut errors, continue);
+///     sum += x;
+/// }
+///
+/// assert_eq!(parse_pair("40,2"), Some((40, 2)));
+/// assert_eq!(parse_pair("40,x"), None);
+/// assert_eq!(parse_positive("42").ok(), Some(42));
+/// assert!(parse_positive("-42").is_err());
+/// assert_eq!(parse_port("8080"), 8080);
+/// assert_eq!(parse_port(":8080"), 8080);
+/// assert_eq!(parse_port("x"), 80);
+/// assert_eq!(sum, 42);
+/// assert_eq!(errors.len(), 1);
+///
+/// let x = None::<i32>;
+/// bind!(x, or return);
+/// unreachable!();
+/// ```
+///
+/// The error about `u8` not implementing `IntoResult` points at `42_u8`:
+/// ```compile_fail
+/// use el_macro::proc::bind;
+///
+/// bind!(x = 42_u8, or return);
+/// ```
+#[proc_macro]
+pub fn bind(input: TokenStream) -> TokenStream {
+    bind::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+
+/// Maps the bound variables of the first matching pattern to [`Some`]
+///
+/// Procedural implementation of the `el_macro::if_matches!` macro, extended to a mini `match`
+/// with several arms. Evaluates the provided expression against the patterns of the arms in order
+/// and yields [`Some`] with the value of the body of the first arm whose pattern matches
+/// and whose optional match guard evaluates to `true`. Yields [`None`] if no arm matches.
+/// The single-arm syntax of `el_macro::if_matches!` is supported as well.
+///
+/// Each body is evaluated as the body of a closure, as with `el_macro::if_matches!`.
+///
+/// # Syntax
+///
+/// ```text
+/// if_matches!(<expression> { <pattern> [if <match-guard>] => <mapping-body> [, ...]* })
+/// if_matches!(<expression>, <pattern> [if <match-guard>] => <mapping-body>)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use el_macro::proc::if_matches;
+///
+/// enum Shape { Circle(f64), Rect(f64, f64), Line }
+///
+/// let area = |shape: Shape| if_matches!(shape {
+///     Shape::Circle(r) => 3.0 * r * r,
+///     Shape::Rect(w, h) if w > 0.0 && h > 0.0 => w * h,
+/// });
+///
+/// assert_eq!(area(Shape::Circle(1.0)), Some(3.0));
+/// assert_eq!(area(Shape::Rect(2.0, 3.0)), Some(6.0));
+/// assert_eq!(area(Shape::Rect(-2.0, 3.0)), None);
+/// assert_eq!(area(Shape::Line), None);
+///
+/// let x = if_matches!(Some(42), Some(x) if x > 0 => x / 2);
+/// assert_eq!(x, Some(21));
+/// ```
+#[proc_macro]
+pub fn if_matches(input: TokenStream) -> TokenStream {
+    if_matches::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+
+/// Turns the `?` operators in the function body into early returns
+///
+/// Brings the ergonomics of the `el_macro::bind!` macro to whole functions that don't return
+/// [`Result`] or [`Option`]. Rewrites every `<value-expr>?` in the body of the annotated function
+/// so that it [tests](https://docs.rs/el-macro/latest/el_macro/bind/trait.IntoResult.html)
+/// whether the value of `<value-expr>` can be unwrapped and yields the unwrapped value if it can.
+/// Otherwise, calls the optional error handler and returns from the function.
+///
+/// The `?` operators inside closures, async blocks and nested items are left as is.
+///
+/// # Syntax
+///
+/// ```text
+/// #[or_return[(<argument> [, <argument>]*)]]
+/// ```
+///
+/// - `handler = <err-handler>` — optional error handler that is called if there's no value
+///   to unwrap, with error object passed as the only argument.
+/// - `value = <return-value>` — optional expression evaluated to the function return value
+///   if there's no value to unwrap. The function must return `()` if not specified.
+/// - `crate = <path>` — optional path to the `el_macro` crate, for the crates
+///   that re-export it or rename the dependency. `::el_macro` if not specified.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::or_return;
+/// #
+/// fn log_err(err: std::num::ParseIntError) {
+///     eprintln!("{err}");
+/// }
+///
+/// #[or_return(handler = log_err)]
+/// fn print_sum(a: &str, b: &str) {
+///     let sum = a.parse::<i32>()? + b.parse::<i32>()?;
+///     println!("{sum}");
+/// }
+///
+/// #[or_return(value = -1)]
+/// fn first_digit(s: &str) -> i32 {
+///     let c = s.chars().next()?;
+///     c.to_digit(10)? as i32
+/// }
+///
+/// // prints 42
+/// print_sum("40", "2");
+/// // prints 'invalid digit found in string'
+/// print_sum("4o", "2");
+///
+/// assert_eq!(first_digit("42"), 4);
+/// assert_eq!(first_digit("x"), -1);
+/// assert_eq!(first_digit(""), -1);
+/// ```
+#[proc_macro_attribute]
+pub fn or_return(attr: TokenStream, item: TokenStream) -> TokenStream {
+    or_return::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+
+/// Sets the default error handler of the `bind!` invocations within the item
+///
+/// Inserts the provided error handler into every `bind!` invocation within the annotated
+/// function, module or any other item that doesn't specify its own error handler.
+/// Both `el_macro::bind!` and `el_macro::proc::bind!` invocations are affected,
+/// as well as any other macro named `bind` with the same syntax.
+///
+/// The handler expression is inserted as is into every affected invocation, so it must be
+/// valid in the scope of each of them.
+///
+/// The invocations with the `throw` flow control expression are left as is, since their
+/// error handler is called with the reference to the error rather than the error itself.
+///
+/// # Syntax
+///
+/// ```text
+/// #[bind_handler(<err-handler>)]
+/// ```
+///
+/// - `<err-handler>` — error handler to insert, as accepted by the `bind!` macro.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, bind_handler};
+/// # use std::cell::Cell;
+/// #
+/// thread_local! {
+///     static FAILURES: Cell<u32> = Cell::new(0);
+/// }
+///
+/// fn report(_err: ()) {
+///     FAILURES.with(|failures| failures.set(failures.get() + 1));
+/// }
+///
+/// #[bind_handler(report)]
+/// fn sum(a: Option<i32>, b: Option<i32>) -> i32 {
+///     bind!(a, or return 0);
+///     bind!(b, or |_| { }, return 0);
+///     a + b
+/// }
+///
+/// assert_eq!(sum(Some(40), Some(2)), 42);
+/// assert_eq!(sum(None, Some(2)), 0);
+/// assert_eq!(sum(Some(40), None), 0);
+/// // the second invocation has its own handler
+/// assert_eq!(FAILURES.with(Cell::get), 1);
+/// ```
+///
+/// The handler isn't inserted into the invocations that throw the error:
+/// ```
+/// # use el_macro::{bind_handler, proc::bind};
+/// # use std::num::ParseIntError;
+/// #
+/// fn log(err: ParseIntError) {
+///     eprintln!("{err}");
+/// }
+///
+/// #[bind_handler(log)]
+/// fn parse_sum(a: &str, b: &str) -> Result<i32, ParseIntError> {
+///     bind!(a = a.parse::<i32>(), or return Ok(0));
+///     bind!(b = b.parse::<i32>(), or throw);
+///     Ok(a + b)
+/// }
+///
+/// assert_eq!(parse_sum("40", "2"), Ok(42));
+/// // prints 'invalid digit found in string'
+/// assert_eq!(parse_sum("4o", "2"), Ok(0));
+/// assert!(parse_sum("40", "2o").is_err());
+/// ```
+#[proc_macro_attribute]
+pub fn bind_handler(attr: TokenStream, item: TokenStream) -> TokenStream {
+    bind_handler::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
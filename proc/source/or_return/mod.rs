@@ -0,0 +1,108 @@
+//! The `#[or_return]` attribute
+
+
+#[cfg(test)]
+mod test;
+
+
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote_spanned};
+use syn::{
+    Expr, Ident, ItemFn, Path, Token,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_quote, parse_quote_spanned, parse2,
+    visit_mut::{self, VisitMut},
+};
+
+
+/// Arguments of the attribute.
+#[derive(Default)]
+struct Args {
+    handler: Option<Expr>,
+    value: Option<Expr>,
+    /// Path to the `el_macro` crate, `::el_macro` if not specified.
+    krate: Option<Path>,
+}
+
+impl Parse for Args {
+
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+        while !input.is_empty() {
+            let key = Ident::parse_any(input)?;
+            input.parse::<Token![=]>()?;
+            let duplicate = match key.to_string().as_str() {
+                "handler" => args.handler.replace(input.parse()?).is_some(),
+                "value" => args.value.replace(input.parse()?).is_some(),
+                "crate" => args.krate.replace(input.parse()?).is_some(),
+                _ => return Err(syn::Error::new(key.span(), "expected `handler`, `value` or `crate`")),
+            };
+            if duplicate {
+                return Err(syn::Error::new(key.span(), format!("duplicate `{key}` argument")));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+
+}
+
+
+/// Replaces the `?` operators with early returns.
+struct Rewriter<'a> {
+    args: &'a Args,
+    krate: Path,
+}
+
+impl VisitMut for Rewriter<'_> {
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        // closures and async blocks have their own `return` targets
+        if matches!(expr, Expr::Closure(_) | Expr::Async(_)) {
+            return;
+        }
+        visit_mut::visit_expr_mut(self, expr);
+        if let Expr::Try(try_expr) = expr {
+            let span = try_expr.question_token.span;
+            let source = &try_expr.expr;
+            let krate = &self.krate;
+            // not visible to the handler and the return value
+            let value = Ident::new("value", Span::mixed_site());
+            let err = Ident::new("err", Span::mixed_site());
+            let handle = self.args.handler.as_ref().map(|handler| quote_spanned! { span=>
+                #krate::private::handle(#handler, #err);
+            });
+            let return_value = &self.args.value;
+            *expr = parse_quote_spanned! { span=>
+                match {
+                    use #krate::bind::IntoResult;
+                    #source.into_result()
+                } {
+                    ::core::result::Result::Ok(#value) => #value,
+                    #[allow(unused_variables)]
+                    ::core::result::Result::Err(#err) => {
+                        #handle
+                        return #return_value;
+                    },
+                }
+            };
+        }
+    }
+
+    fn visit_item_mut(&mut self, _item: &mut syn::Item) {
+        // nested items have their own `return` targets
+    }
+
+}
+
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let args: Args = parse2(attr)?;
+    let mut function: ItemFn = parse2(item)?;
+    let krate = args.krate.clone().unwrap_or_else(|| parse_quote! { ::el_macro });
+    Rewriter { args: &args, krate }.visit_block_mut(&mut function.block);
+    Ok(function.into_token_stream())
+}
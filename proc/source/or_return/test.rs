@@ -0,0 +1,70 @@
+use quote::{ToTokens, quote};
+use syn::{ItemFn, parse2};
+
+use super::{Args, expand};
+
+
+#[test]
+fn arguments() {
+
+    let args: Args = parse2(quote! { handler = log, value = -1, crate = reexport::el_macro }).unwrap();
+    assert!(args.handler.is_some());
+    assert!(args.value.is_some());
+    assert_eq!(args.krate.map(|path| quote! { #path }.to_string()), Some("reexport :: el_macro".into()));
+
+    for tokens in [
+        quote! { handler = log, handler = log },
+        quote! { crate = el_macro, crate = el_macro },
+        quote! { and = log },
+        quote! { value },
+    ] {
+        assert!(parse2::<Args>(tokens).is_err());
+    }
+
+}
+
+
+#[test]
+fn crate_path() {
+
+    let item = quote! { fn f() { g()?; } };
+
+    let expansion = expand(quote! {}, item.clone()).unwrap().to_string();
+    assert!(expansion.contains(&quote! { use ::el_macro::bind::IntoResult; }.to_string()));
+
+    let expansion = expand(quote! { crate = reexport::el_macro }, item).unwrap().to_string();
+    assert!(expansion.contains(&quote! { use reexport::el_macro::bind::IntoResult; }.to_string()));
+
+}
+
+
+#[test]
+fn nested_return_targets_are_left_as_is() {
+
+    let item = quote! { fn f() { let g = || h()?; async { h()? }; fn i() { h()?; } } };
+    let expansion = expand(quote! {}, item.clone()).unwrap();
+    let unchanged = parse2::<ItemFn>(item).unwrap().into_token_stream();
+    assert_eq!(expansion.to_string(), unchanged.to_string());
+
+}
+
+
+#[test]
+fn internal_bindings_are_hygienic() {
+
+    use el_macro::or_return;
+
+    #[or_return(handler = |_| errors.push(err), value = value)]
+    fn first_digit(s: &str, errors: &mut Vec<&'static str>) -> Option<u32> {
+        let err = "not a digit";
+        let value = None;
+        let c = s.chars().next()?;
+        Some(c.to_digit(10)?)
+    }
+
+    let mut errors = Vec::new();
+    assert_eq!(first_digit("42", &mut errors), Some(4));
+    assert_eq!(first_digit("x", &mut errors), None);
+    assert_eq!(errors, ["not a digit"]);
+
+}
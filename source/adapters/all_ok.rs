@@ -0,0 +1,48 @@
+use crate::{bind::IntoResult, error::IndexedError};
+
+
+/// Unwraps all the items of a collection, failing on the first one that can't be unwrapped.
+///
+/// The value is the [`Vec`] of the unwrapped values of the items of the provided
+/// [`IntoIterator`]. The error is the error of the first item that can't be unwrapped,
+/// along with its index. The items after the failed one are not consumed.
+///
+/// Used by the [`crate::bind_all!`] macro.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::{bind, adapters::AllOk, error::IndexedError};
+/// #
+/// let report = |err: IndexedError<()>| eprintln!("item {} is none", err.index);
+///
+/// bind!(xs = AllOk(vec![Some(1), Some(2)]), or return);
+/// assert_eq!(xs, [1, 2]);
+///
+/// // prints 'item 1 is none' and returns
+/// bind!(xs = AllOk(vec![Some(1), None, Some(3)]), or report, return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct AllOk<I>(pub I);
+
+impl<I> IntoResult for AllOk<I>
+where
+    I: IntoIterator,
+    I::Item: IntoResult,
+{
+
+    type Value = Vec<<I::Item as IntoResult>::Value>;
+    type Error = IndexedError<<I::Item as IntoResult>::Error>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.into_iter()
+            .enumerate()
+            .map(|(index, item)| item.into_result().map_err(|error| IndexedError { index, error }))
+            .collect()
+    }
+
+}
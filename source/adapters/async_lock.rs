@@ -0,0 +1,163 @@
+use std::sync::TryLockError;
+
+use async_lock::{
+    Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard, Semaphore, SemaphoreGuard,
+};
+
+use crate::bind::IntoResult;
+
+
+/// Locks the `async-lock` [`Mutex`] only if it's immediately available.
+///
+/// The value is the guard, the error is [`TryLockError::WouldBlock`], same as for
+/// the [`TryLock`](super::TryLock) adapter of the std [`Mutex`](std::sync::Mutex).
+/// The `async-lock` primitives aren't poisoned, so there's no other error.
+///
+/// Available with the `async-lock` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryLockAsync};
+/// # use async_lock::Mutex;
+/// #
+/// let counter = Mutex::new(42);
+///
+/// bind!(guard = TryLockAsync(&counter), or return);
+/// assert_eq!(*guard, 42);
+///
+/// // prints 'try_lock failed because the operation would block' and returns
+/// bind!(guard = TryLockAsync(&counter), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct TryLockAsync<'a, T>(pub &'a Mutex<T>);
+
+impl<'a, T> IntoResult for TryLockAsync<'a, T> {
+
+    type Value = MutexGuard<'a, T>;
+    type Error = TryLockError<Self::Value>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.try_lock().ok_or(TryLockError::WouldBlock)
+    }
+
+}
+
+
+/// Locks the `async-lock` [`RwLock`] with shared read access only if it's immediately available.
+///
+/// The value is the read guard, the error is [`TryLockError::WouldBlock`],
+/// same as for [`TryLockAsync`].
+///
+/// Available with the `async-lock` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryReadAsync};
+/// # use async_lock::RwLock;
+/// #
+/// let config = RwLock::new("debug");
+///
+/// bind!(first = TryReadAsync(&config), or return);
+/// bind!(second = TryReadAsync(&config), or return);
+/// assert_eq!(*first, *second);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct TryReadAsync<'a, T>(pub &'a RwLock<T>);
+
+impl<'a, T> IntoResult for TryReadAsync<'a, T> {
+
+    type Value = RwLockReadGuard<'a, T>;
+    type Error = TryLockError<Self::Value>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.try_read().ok_or(TryLockError::WouldBlock)
+    }
+
+}
+
+
+/// Locks the `async-lock` [`RwLock`] with exclusive write access only if it's immediately available.
+///
+/// The value is the write guard, the error is [`TryLockError::WouldBlock`],
+/// same as for [`TryLockAsync`].
+///
+/// Available with the `async-lock` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::{TryReadAsync, TryWriteAsync}};
+/// # use async_lock::RwLock;
+/// #
+/// let config = RwLock::new("debug");
+///
+/// {
+///     bind!(mut guard = TryWriteAsync(&config), or return);
+///     *guard = "release";
+/// }
+///
+/// bind!(reader = TryReadAsync(&config), or return);
+/// // returns, since there's a reader
+/// bind!(writer = TryWriteAsync(&config), or return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct TryWriteAsync<'a, T>(pub &'a RwLock<T>);
+
+impl<'a, T> IntoResult for TryWriteAsync<'a, T> {
+
+    type Value = RwLockWriteGuard<'a, T>;
+    type Error = TryLockError<Self::Value>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.try_write().ok_or(TryLockError::WouldBlock)
+    }
+
+}
+
+
+/// Acquires a permit of the `async-lock` [`Semaphore`] only if one is immediately available.
+///
+/// The value is the guard releasing the permit once dropped, the error is the same
+/// as the [`IntoResult::Error`] of [`Option`].
+///
+/// Available with the `async-lock` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryAcquire};
+/// # use async_lock::Semaphore;
+/// #
+/// let slots = Semaphore::new(2);
+///
+/// bind!(first = TryAcquire(&slots), or return);
+/// bind!(second = TryAcquire(&slots), or return);
+/// // returns, since both permits are held
+/// bind!(third = TryAcquire(&slots), or return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct TryAcquire<'a>(pub &'a Semaphore);
+
+impl<'a> IntoResult for TryAcquire<'a> {
+
+    type Value = SemaphoreGuard<'a>;
+    type Error = <Option<Self::Value> as IntoResult>::Error;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.try_acquire().into_result()
+    }
+
+}
@@ -0,0 +1,109 @@
+use std::{
+    convert::Infallible,
+    fmt,
+    ops::{ControlFlow, FromResidual, Try},
+};
+
+use crate::bind::IntoResult;
+
+
+/// Makes any [`IntoResult`] type usable with the `?` operator.
+///
+/// Created by the [`Bindable()`] function. Implements [`Try`] with [`Result`] as the residual,
+/// so the `?` operator applied to it returns the error, [converted](From) to the error type
+/// of the enclosing function returning [`Result`]. Implements [`IntoResult`] as well,
+/// so a single [`IntoResult`] implementation serves both the [`crate::bind!`] macro
+/// and the `?` operator.
+///
+/// Available with the `nightly-try` feature, which requires a nightly compiler.
+#[must_use]
+pub struct Bindable<T: IntoResult> {
+    result: Result<T::Value, T::Error>,
+}
+
+/// Wraps the [`IntoResult`] value into [`Bindable`](struct@Bindable).
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{adapters::Bindable, bind::IntoResult};
+/// #
+/// struct NegativeIsError(i32);
+///
+/// impl IntoResult for NegativeIsError {
+///
+///     type Value = i32;
+///     type Error = i32;
+///
+///     fn into_result(self) -> Result<Self::Value, Self::Error> {
+///         if self.0 >= 0 { Ok(self.0) } else { Err(self.0) }
+///     }
+///
+/// }
+///
+/// fn sum(a: i32, b: i32) -> Result<i32, i64> {
+///     Ok(Bindable(NegativeIsError(a))? + Bindable(NegativeIsError(b))?)
+/// }
+///
+/// assert_eq!(sum(40, 2), Ok(42));
+/// assert_eq!(sum(40, -1), Err(-1));
+/// ```
+#[allow(non_snake_case)]
+#[inline(always)]
+pub fn Bindable<T: IntoResult>(source: T) -> Bindable<T> {
+    Bindable { result: source.into_result() }
+}
+
+impl<T: IntoResult> Try for Bindable<T> {
+
+    type Output = T::Value;
+    type Residual = Result<Infallible, T::Error>;
+
+    fn from_output(output: Self::Output) -> Self {
+        Self { result: Ok(output) }
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self.result {
+            Ok(value) => ControlFlow::Continue(value),
+            Err(err) => ControlFlow::Break(Err(err)),
+        }
+    }
+
+}
+
+impl<T: IntoResult> FromResidual<Result<Infallible, T::Error>> for Bindable<T> {
+
+    fn from_residual(residual: Result<Infallible, T::Error>) -> Self {
+        match residual {
+            Ok(never) => match never { },
+            Err(err) => Self { result: Err(err) },
+        }
+    }
+
+}
+
+impl<T: IntoResult> IntoResult for Bindable<T> {
+
+    type Value = T::Value;
+    type Error = T::Error;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.result
+    }
+
+}
+
+impl<T> fmt::Debug for Bindable<T>
+where
+    T: IntoResult,
+    T::Value: fmt::Debug,
+    T::Error: fmt::Debug,
+{
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Bindable").field(&self.result).finish()
+    }
+
+}
@@ -0,0 +1,114 @@
+use crate::{bind::IntoResult, error::Invalid};
+
+
+/// Converts the code point into a [`char`].
+///
+/// The value is the [`char`] if the code point is valid, as with [`char::from_u32`].
+/// Otherwise, the error is the [`Invalid`] error holding the code point.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::FromU32};
+/// #
+/// bind!(c = FromU32(0x2A), or return);
+/// assert_eq!(c, '*');
+///
+/// // prints '55296 fails the check `char::from_u32`' and returns
+/// bind!(c = FromU32(0xD800), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct FromU32(pub u32);
+
+impl IntoResult for FromU32 {
+
+    type Value = char;
+    type Error = Invalid<u32>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        char::from_u32(self.0).ok_or(Invalid { value: self.0, check: "char::from_u32" })
+    }
+
+}
+
+
+/// Converts the digit in the radix into a [`char`].
+///
+/// The value is the [`char`] if the digit is less than the radix, as with [`char::from_digit`].
+/// Otherwise, the error is the [`Invalid`] error holding the digit and the radix.
+///
+/// Panics if the radix is greater than 36, as [`char::from_digit`] does.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::FromDigit};
+/// #
+/// bind!(c = FromDigit(11, 16), or return);
+/// assert_eq!(c, 'b');
+///
+/// // prints '(11, 10) fails the check `char::from_digit`' and returns
+/// bind!(c = FromDigit(11, 10), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct FromDigit(pub u32, pub u32);
+
+impl IntoResult for FromDigit {
+
+    type Value = char;
+    type Error = Invalid<(u32, u32)>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let Self(digit, radix) = self;
+        char::from_digit(digit, radix)
+            .ok_or(Invalid { value: (digit, radix), check: "char::from_digit" })
+    }
+
+}
+
+
+/// Converts the [`char`] into a digit in the radix.
+///
+/// The value is the digit if the [`char`] is a digit in the radix, as with [`char::to_digit`].
+/// Otherwise, the error is the [`Invalid`] error holding the [`char`] and the radix.
+///
+/// Panics if the radix is not in the range from 2 to 36, as [`char::to_digit`] does.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::ToDigit};
+/// #
+/// let mut sum = 0;
+/// for c in "4x2".chars() {
+///     bind!(digit = ToDigit(c, 10), or continue);
+///     sum += digit;
+/// }
+/// assert_eq!(sum, 6);
+///
+/// // prints "('x', 16) fails the check `char::to_digit`" and returns
+/// bind!(digit = ToDigit('x', 16), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct ToDigit(pub char, pub u32);
+
+impl IntoResult for ToDigit {
+
+    type Value = u32;
+    type Error = Invalid<(char, u32)>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let Self(c, radix) = self;
+        c.to_digit(radix).ok_or(Invalid { value: (c, radix), check: "char::to_digit" })
+    }
+
+}
@@ -0,0 +1,158 @@
+use crate::{bind::IntoResult, error::ArithmeticError};
+
+
+/// Checked arithmetic of the primitive integer types.
+///
+/// Used by the [`CheckedAdd`], [`CheckedSub`], [`CheckedMul`] and [`CheckedDiv`] adapters
+/// to be generic over the integer type.
+pub trait CheckedArithmetic: Copy {
+
+    /// Same as the inherent `checked_add` method of the integer type.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Same as the inherent `checked_sub` method of the integer type.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Same as the inherent `checked_mul` method of the integer type.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Same as the inherent `checked_div` method of the integer type.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+
+}
+
+macro_rules! impl_checked_arithmetic {
+
+    ($($t: ty)+) => {
+        $(
+            impl CheckedArithmetic for $t {
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_div(self, rhs)
+                }
+
+            }
+        )+
+    };
+
+}
+
+impl_checked_arithmetic!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+
+macro_rules! checked_adapter {
+
+    ($(#[$a: meta])* $name: ident, $method: ident, $op: literal) => {
+        $(#[$a])*
+        #[derive(Clone, Copy, Debug)]
+        #[must_use]
+        pub struct $name<T>(pub T, pub T);
+
+        impl<T: CheckedArithmetic> IntoResult for $name<T> {
+
+            type Value = T;
+            type Error = ArithmeticError<T>;
+
+            #[inline(always)]
+            fn into_result(self) -> Result<Self::Value, Self::Error> {
+                let Self(lhs, rhs) = self;
+                lhs.$method(rhs).ok_or(ArithmeticError { op: $op, lhs, rhs })
+            }
+
+        }
+    };
+
+}
+
+
+checked_adapter! {
+    /// Adds the integers, failing on overflow.
+    ///
+    /// The value is the sum, the error is the [`ArithmeticError`] holding the operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use el_macro::{bind, adapters::CheckedAdd};
+    /// #
+    /// bind!(total = CheckedAdd(40_u8, 2), or return);
+    /// assert_eq!(total, 42);
+    ///
+    /// // prints '`200 + 100` overflows' and returns
+    /// bind!(total = CheckedAdd(200_u8, 100), or |err| eprintln!("{err}"), return);
+    /// unreachable!();
+    /// ```
+    CheckedAdd, checked_add, "+"
+}
+
+
+checked_adapter! {
+    /// Subtracts the integers, failing on overflow.
+    ///
+    /// The value is the difference, the error is the [`ArithmeticError`] holding the operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use el_macro::{bind, adapters::CheckedSub};
+    /// #
+    /// bind!(left = CheckedSub(44_u32, 2), or return);
+    /// assert_eq!(left, 42);
+    ///
+    /// // prints '`2 - 44` overflows' and returns
+    /// bind!(left = CheckedSub(2_u32, 44), or |err| eprintln!("{err}"), return);
+    /// unreachable!();
+    /// ```
+    CheckedSub, checked_sub, "-"
+}
+
+
+checked_adapter! {
+    /// Multiplies the integers, failing on overflow.
+    ///
+    /// The value is the product, the error is the [`ArithmeticError`] holding the operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use el_macro::{bind, adapters::CheckedMul};
+    /// #
+    /// bind!(area = CheckedMul(6_i16, 7), or return);
+    /// assert_eq!(area, 42);
+    ///
+    /// // prints '`300 * 300` overflows' and returns
+    /// bind!(area = CheckedMul(300_i16, 300), or |err| eprintln!("{err}"), return);
+    /// unreachable!();
+    /// ```
+    CheckedMul, checked_mul, "*"
+}
+
+
+checked_adapter! {
+    /// Divides the integers, failing on division by zero or overflow.
+    ///
+    /// The value is the quotient, the error is the [`ArithmeticError`] holding the operands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use el_macro::{bind, adapters::CheckedDiv};
+    /// #
+    /// bind!(share = CheckedDiv(84_i64, 2), or return);
+    /// assert_eq!(share, 42);
+    ///
+    /// // prints '`84 / 0` divides by zero or overflows' and returns
+    /// bind!(share = CheckedDiv(84_i64, 0), or |err| eprintln!("{err}"), return);
+    /// unreachable!();
+    /// ```
+    CheckedDiv, checked_div, "/"
+}
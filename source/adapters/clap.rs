@@ -0,0 +1,119 @@
+use std::{any::Any, marker::PhantomData};
+
+use clap::{parser::{MatchesError, ValuesRef}, ArgMatches};
+
+use crate::{bind::IntoResult, error::ArgError};
+
+
+/// Gets the value of the `clap` argument as `T`.
+///
+/// The value is the reference to the value of the argument, the error is the [`ArgError`]
+/// naming the argument, with the [`MatchesError`] of [`ArgMatches::try_get_one`]
+/// or without one if the argument is missing. Created with the [`ArgOne()`] function.
+///
+/// Available with the `clap` feature.
+#[must_use]
+pub struct ArgOne<'a, T> {
+    matches: &'a ArgMatches,
+    arg: &'a str,
+    target: PhantomData<fn() -> T>,
+}
+
+/// Creates the [`ArgOne`] adapter getting the value of the argument as `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::ArgOne};
+/// # use clap::{value_parser, Arg, Command};
+/// #
+/// let matches = Command::new("serve")
+///     .arg(Arg::new("port").long("port").value_parser(value_parser!(u16)))
+///     .arg(Arg::new("host").long("host"))
+///     .get_matches_from(["serve", "--port", "8080"]);
+///
+/// bind!(port = ArgOne::<u16>(&matches, "port"), or std::process::exit(2));
+/// assert_eq!(*port, 8080);
+///
+/// // prints 'argument `host` is missing' and returns
+/// bind!(host = ArgOne::<String>(&matches, "host"), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[allow(non_snake_case)]
+#[inline(always)]
+pub fn ArgOne<'a, T>(matches: &'a ArgMatches, arg: &'a str) -> ArgOne<'a, T> {
+    ArgOne { matches, arg, target: PhantomData }
+}
+
+impl<'a, T: Any + Clone + Send + Sync + 'static> IntoResult for ArgOne<'a, T> {
+
+    type Value = &'a T;
+    type Error = ArgError<MatchesError>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self.matches.try_get_one(self.arg) {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(ArgError { arg: self.arg.to_owned(), error: None }),
+            Err(error) => Err(ArgError { arg: self.arg.to_owned(), error: Some(error) }),
+        }
+    }
+
+}
+
+
+/// Gets the values of the `clap` argument as `T`.
+///
+/// The value is the iterator over the references to the values of the argument,
+/// the error is the [`ArgError`] naming the argument, with the [`MatchesError`]
+/// of [`ArgMatches::try_get_many`] or without one if the argument is missing.
+/// Created with the [`ArgMany()`] function.
+///
+/// Available with the `clap` feature.
+#[must_use]
+pub struct ArgMany<'a, T> {
+    matches: &'a ArgMatches,
+    arg: &'a str,
+    target: PhantomData<fn() -> T>,
+}
+
+/// Creates the [`ArgMany`] adapter getting the values of the argument as `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::ArgMany};
+/// # use clap::{Arg, ArgAction, Command};
+/// #
+/// let matches = Command::new("cat")
+///     .arg(Arg::new("files").action(ArgAction::Append))
+///     .get_matches_from(["cat", "a.txt", "b.txt"]);
+///
+/// bind!(files = ArgMany::<String>(&matches, "files"), or std::process::exit(2));
+/// assert_eq!(files.collect::<Vec<_>>(), ["a.txt", "b.txt"]);
+///
+/// // prints 'argument `files`: Mismatch between definition and access of `files`. …' and returns
+/// bind!(files = ArgMany::<u32>(&matches, "files"), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[allow(non_snake_case)]
+#[inline(always)]
+pub fn ArgMany<'a, T>(matches: &'a ArgMatches, arg: &'a str) -> ArgMany<'a, T> {
+    ArgMany { matches, arg, target: PhantomData }
+}
+
+impl<'a, T: Any + Clone + Send + Sync + 'static> IntoResult for ArgMany<'a, T> {
+
+    type Value = ValuesRef<'a, T>;
+    type Error = ArgError<MatchesError>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self.matches.try_get_many(self.arg) {
+            Ok(Some(values)) => Ok(values),
+            Ok(None) => Err(ArgError { arg: self.arg.to_owned(), error: None }),
+            Err(error) => Err(ArgError { arg: self.arg.to_owned(), error: Some(error) }),
+        }
+    }
+
+}
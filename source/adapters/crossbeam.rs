@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use crossbeam_channel::{Receiver, RecvError, RecvTimeoutError, SelectedOperation, TryRecvError};
+
+use crate::bind::IntoResult;
+
+
+/// Receives from the `crossbeam-channel` [`Receiver`] only if a message is immediately available.
+///
+/// The value is the message, the error is the [`TryRecvError`], telling whether the channel
+/// is empty or disconnected.
+///
+/// Available with the `crossbeam` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryRecv};
+/// # use crossbeam_channel::unbounded;
+/// #
+/// let (tx, rx) = unbounded();
+/// tx.send(42).unwrap();
+///
+/// bind!(x = TryRecv(&rx), or return);
+/// assert_eq!(x, 42);
+///
+/// // prints 'receiving on an empty channel' and returns
+/// bind!(x = TryRecv(&rx), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct TryRecv<'a, T>(pub &'a Receiver<T>);
+
+impl<'a, T> IntoResult for TryRecv<'a, T> {
+
+    type Value = T;
+    type Error = TryRecvError;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.try_recv()
+    }
+
+}
+
+
+/// Receives from the `crossbeam-channel` [`Receiver`], waiting for a message
+/// for at most the given duration.
+///
+/// The value is the message, the error is the [`RecvTimeoutError`], telling whether
+/// the wait timed out or the channel is disconnected.
+///
+/// Available with the `crossbeam` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::RecvTimeout};
+/// # use crossbeam_channel::{unbounded, RecvTimeoutError};
+/// # use std::time::Duration;
+/// #
+/// let (tx, rx) = unbounded::<i32>();
+///
+/// let timeout = Duration::from_millis(1);
+/// 'poll: {
+///     bind!(x = RecvTimeout(&rx, timeout), or |err| assert_eq!(err, RecvTimeoutError::Timeout), break 'poll);
+///     unreachable!();
+/// }
+///
+/// tx.send(42).unwrap();
+/// bind!(x = RecvTimeout(&rx, timeout), or return);
+/// assert_eq!(x, 42);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct RecvTimeout<'a, T>(pub &'a Receiver<T>, pub Duration);
+
+impl<'a, T> IntoResult for RecvTimeout<'a, T> {
+
+    type Value = T;
+    type Error = RecvTimeoutError;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.recv_timeout(self.1)
+    }
+
+}
+
+
+/// Completes the receive operation selected by the `crossbeam-channel`
+/// [`Select`](crossbeam_channel::Select).
+///
+/// The value is the message, the error is the [`RecvError`] if the channel is disconnected.
+/// The [`Receiver`] must be the one the operation was added to the `Select` for.
+///
+/// Available with the `crossbeam` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::SelectedRecv};
+/// # use crossbeam_channel::{unbounded, Select};
+/// #
+/// let (_tx1, rx1) = unbounded::<i32>();
+/// let (tx2, rx2) = unbounded();
+/// tx2.send(42).unwrap();
+///
+/// let mut select = Select::new();
+/// let first = select.recv(&rx1);
+/// let second = select.recv(&rx2);
+///
+/// let operation = select.select();
+/// let rx = match operation.index() {
+///     index if index == first => &rx1,
+///     index if index == second => &rx2,
+///     _ => unreachable!(),
+/// };
+/// bind!(x = SelectedRecv(operation, rx), or return);
+/// assert_eq!(x, 42);
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct SelectedRecv<'a, T>(pub SelectedOperation<'a>, pub &'a Receiver<T>);
+
+impl<'a, T> IntoResult for SelectedRecv<'a, T> {
+
+    type Value = T;
+    type Error = RecvError;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.recv(self.1)
+    }
+
+}
@@ -0,0 +1,113 @@
+use std::{any::Any, fmt, marker::PhantomData};
+
+use crate::bind::IntoResult;
+
+
+/// Downcasts the [`Box<dyn Any>`](Any) or the `&dyn Any` reference to the concrete type `T`.
+///
+/// The value is the box or the reference of the concrete type, the error is the original box
+/// or reference, given back on type mismatch so that another type can be tried.
+/// Implemented for the boxes of `dyn Any`, `dyn Any + Send` and `dyn Any + Send + Sync`,
+/// and for the shared and mutable references to `dyn Any`.
+///
+/// A single-type counterpart to the [`crate::match_downcast!`] macro.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::Downcast};
+/// # use std::any::Any;
+/// #
+/// let payload: Box<dyn Any> = Box::new(String::from("el-macro"));
+///
+/// // gets the box back and tries another type
+/// bind!(number = Downcast::<i32, _>::new(payload), or |payload: Box<dyn Any>| {
+///     bind!(text = Downcast::<String, _>::new(payload), or return);
+///     assert_eq!(*text, "el-macro");
+/// }, return);
+/// unreachable!();
+/// ```
+///
+/// ```
+/// # use el_macro::{bind, adapters::Downcast};
+/// # use std::any::Any;
+/// #
+/// let value: &dyn Any = &42_u8;
+/// bind!(byte = Downcast::<u8, _>::new(value), or return);
+/// assert_eq!(*byte, 42);
+/// ```
+#[must_use]
+pub struct Downcast<T, S>(S, PhantomData<fn() -> T>);
+
+impl<T, S> Downcast<T, S> {
+
+    /// Creates the adapter downcasting the provided box or reference to `T`.
+    #[inline(always)]
+    pub fn new(source: S) -> Self {
+        Self(source, PhantomData)
+    }
+
+    /// Gives back the box or the reference to downcast.
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+
+}
+
+impl<T, S: fmt::Debug> fmt::Debug for Downcast<T, S> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Downcast").field(&self.0).finish()
+    }
+
+}
+
+macro_rules! impl_downcast_box {
+
+    ($($any: ty),+) => {
+        $(
+            impl<T: Any> IntoResult for Downcast<T, Box<$any>> {
+
+                type Value = Box<T>;
+                type Error = Box<$any>;
+
+                #[inline(always)]
+                fn into_result(self) -> Result<Self::Value, Self::Error> {
+                    self.0.downcast()
+                }
+
+            }
+        )+
+    };
+
+}
+
+impl_downcast_box!(dyn Any, dyn Any + Send, dyn Any + Send + Sync);
+
+impl<'a, T: Any> IntoResult for Downcast<T, &'a dyn Any> {
+
+    type Value = &'a T;
+    type Error = &'a dyn Any;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.downcast_ref().ok_or(self.0)
+    }
+
+}
+
+impl<'a, T: Any> IntoResult for Downcast<T, &'a mut dyn Any> {
+
+    type Value = &'a mut T;
+    type Error = &'a mut dyn Any;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        if self.0.is::<T>() {
+            Ok(self.0.downcast_mut().expect("the type is checked"))
+        } else {
+            Err(self.0)
+        }
+    }
+
+}
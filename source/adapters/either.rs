@@ -0,0 +1,45 @@
+use either::Either;
+
+use crate::bind::IntoResult;
+
+
+/// Treats the `Left` variant of an [`Either`] as the value and the `Right` one as the error.
+///
+/// Reverses the [`IntoResult`] implementation for [`Either`] for the APIs
+/// that put the successful outcome on the left.
+///
+/// Available with the `either` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::LeftValue};
+/// # use either::Either;
+/// #
+/// let found = Either::<i32, &str>::Left(42);
+/// bind!(x = LeftValue(found), or return);
+/// assert_eq!(x, 42);
+///
+/// // prints 'missing' and returns
+/// let found = Either::<i32, &str>::Right("missing");
+/// bind!(x = LeftValue(found), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct LeftValue<L, R>(pub Either<L, R>);
+
+impl<L, R> IntoResult for LeftValue<L, R> {
+
+    type Value = L;
+    type Error = R;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self.0 {
+            Either::Left(value) => Ok(value),
+            Either::Right(err) => Err(err),
+        }
+    }
+
+}
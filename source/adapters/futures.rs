@@ -0,0 +1,171 @@
+use std::{pin::Pin, sync::mpsc::TryRecvError, task::{Context, Poll}};
+
+use futures::{TryFuture, channel::{mpsc, oneshot}, task::noop_waker_ref};
+
+use crate::{bind::IntoResult, error::NotReady};
+
+
+/// Receives from the `futures` [`oneshot::Receiver`] only if the message has been sent.
+///
+/// The value is the message. Flattens the outcome of [`oneshot::Receiver::try_recv`]
+/// into the error, which is [`TryRecvError::Empty`] if the message hasn't been sent yet
+/// or [`TryRecvError::Disconnected`] if the sender has been dropped without sending it.
+///
+/// Available with the `futures` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryRecvOneshot};
+/// # use futures::channel::oneshot;
+/// # use std::sync::mpsc::TryRecvError;
+/// #
+/// let (tx, mut rx) = oneshot::channel();
+///
+/// 'poll: {
+///     bind!(x = TryRecvOneshot(&mut rx), or |err| assert_eq!(err, TryRecvError::Empty), break 'poll);
+///     unreachable!();
+/// }
+///
+/// tx.send(42).unwrap();
+/// bind!(x = TryRecvOneshot(&mut rx), or return);
+/// assert_eq!(x, 42);
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct TryRecvOneshot<'a, T>(pub &'a mut oneshot::Receiver<T>);
+
+impl<'a, T> IntoResult for TryRecvOneshot<'a, T> {
+
+    type Value = T;
+    type Error = TryRecvError;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self.0.try_recv() {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(TryRecvError::Empty),
+            Err(oneshot::Canceled) => Err(TryRecvError::Disconnected),
+        }
+    }
+
+}
+
+
+/// Receives from the `futures` [`mpsc::Receiver`] or [`mpsc::UnboundedReceiver`]
+/// only if a message is immediately available.
+///
+/// The value is the message. Flattens the outcome of `try_next` into the error,
+/// which is [`TryRecvError::Empty`] if there's no message yet
+/// or [`TryRecvError::Disconnected`] if the channel is closed.
+///
+/// Available with the `futures` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryNext};
+/// # use futures::channel::mpsc;
+/// #
+/// let (tx, mut rx) = mpsc::unbounded();
+/// tx.unbounded_send(42).unwrap();
+/// drop(tx);
+///
+/// bind!(x = TryNext(&mut rx), or return);
+/// assert_eq!(x, 42);
+///
+/// // prints 'receiving on a closed channel' and returns
+/// bind!(x = TryNext(&mut rx), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct TryNext<'a, R>(pub &'a mut R);
+
+impl<'a, T> IntoResult for TryNext<'a, mpsc::Receiver<T>> {
+
+    type Value = T;
+    type Error = TryRecvError;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        // superseded by `try_recv`, which is missing from the earlier 0.3 releases
+        #[allow(deprecated)]
+        match self.0.try_next() {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(TryRecvError::Disconnected),
+            Err(_) => Err(TryRecvError::Empty),
+        }
+    }
+
+}
+
+impl<'a, T> IntoResult for TryNext<'a, mpsc::UnboundedReceiver<T>> {
+
+    type Value = T;
+    type Error = TryRecvError;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        // superseded by `try_recv`, which is missing from the earlier 0.3 releases
+        #[allow(deprecated)]
+        match self.0.try_next() {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err(TryRecvError::Disconnected),
+            Err(_) => Err(TryRecvError::Empty),
+        }
+    }
+
+}
+
+
+/// Polls the `futures` [`TryFuture`] once, without waiting for it to complete.
+///
+/// The value is the success of the future. The error is [`NotReady::Pending`] if the future
+/// hasn't completed yet, so it may be polled again later, or [`NotReady::Failed`] with
+/// the error the future completed with. The future is polled with a waker that does nothing,
+/// so it isn't woken up once it can make progress.
+///
+/// Available with the `futures` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryNowOrNever, error::NotReady};
+/// # use futures::channel::oneshot;
+/// #
+/// let (tx, mut rx) = oneshot::channel();
+///
+/// 'poll: {
+///     bind!(x = TryNowOrNever(&mut rx), or |err| assert_eq!(err, NotReady::Pending), break 'poll);
+///     unreachable!();
+/// }
+///
+/// tx.send(42).unwrap();
+/// bind!(x = TryNowOrNever(&mut rx), or return);
+/// assert_eq!(x, 42);
+///
+/// let mut failing = futures::future::err::<i32, _>("failed");
+/// bind!(x = TryNowOrNever(&mut failing), or |err| assert_eq!(err, NotReady::Failed("failed")), return);
+/// unreachable!();
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct TryNowOrNever<'a, F>(pub &'a mut F);
+
+impl<'a, F: TryFuture + Unpin> IntoResult for TryNowOrNever<'a, F> {
+
+    type Value = F::Ok;
+    type Error = NotReady<F::Error>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let mut cx = Context::from_waker(noop_waker_ref());
+        match Pin::new(self.0).try_poll(&mut cx) {
+            Poll::Ready(Ok(value)) => Ok(value),
+            Poll::Ready(Err(err)) => Err(NotReady::Failed(err)),
+            Poll::Pending => Err(NotReady::Pending),
+        }
+    }
+
+}
@@ -0,0 +1,49 @@
+use http::Response;
+
+use crate::{bind::IntoResult, error::StatusError};
+
+
+/// Requires the `http` [`Response`] to have a successful status.
+///
+/// The value is the body of the response with a `2xx` status, the error is
+/// the [`StatusError`] holding the status code and the body of the response otherwise.
+///
+/// Available with the `http` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::SuccessOf};
+/// # use http::Response;
+/// #
+/// let ok = Response::new("42");
+/// bind!(body = SuccessOf(ok), or return);
+/// assert_eq!(body, "42");
+///
+/// let not_found = Response::builder().status(404).body("no such user").unwrap();
+///
+/// // prints 'unsuccessful status 404' and returns
+/// bind!(body = SuccessOf(not_found), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct SuccessOf<T>(pub Response<T>);
+
+impl<T> IntoResult for SuccessOf<T> {
+
+    type Value = T;
+    type Error = StatusError<T>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let status = self.0.status();
+        let body = self.0.into_body();
+        if status.is_success() {
+            Ok(body)
+        } else {
+            Err(StatusError { status: status.as_u16(), body })
+        }
+    }
+
+}
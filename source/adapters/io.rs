@@ -0,0 +1,125 @@
+use std::{io, process::{Child, ExitStatus}};
+
+use crate::{bind::IntoResult, error::NotFinished};
+
+
+/// Treats the [`NotFound`](io::ErrorKind::NotFound) error of an [`io::Result`] as an empty value.
+///
+/// The value is [`Some`] with the value of the result, or [`None`] if the result is
+/// an [`io::Error`] of the [`NotFound`](io::ErrorKind::NotFound) kind.
+/// The errors of other kinds are passed through.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::NotFoundAsNone};
+/// #
+/// let missing = std::fs::read_to_string("/definitely/not/there");
+/// bind!(config = NotFoundAsNone(missing), or return);
+/// assert!(config.is_none());
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct NotFoundAsNone<T>(pub io::Result<T>);
+
+impl<T> IntoResult for NotFoundAsNone<T> {
+
+    type Value = Option<T>;
+    type Error = io::Error;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self.0 {
+            Ok(value) => Ok(Some(value)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+}
+
+
+/// Retries an I/O operation as long as it fails with the
+/// [`Interrupted`](io::ErrorKind::Interrupted) error.
+///
+/// Calls the provided closure until it returns a value or an error of another kind,
+/// which are the value and the error of the adapter.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::InterruptedRetried};
+/// # use std::io::Read;
+/// #
+/// let mut input = "hello".as_bytes();
+/// let mut buf = [0; 8];
+/// bind!(n = InterruptedRetried(|| input.read(&mut buf)), or return);
+/// assert_eq!(&buf[..n], b"hello");
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct InterruptedRetried<F>(pub F);
+
+impl<F, T> IntoResult for InterruptedRetried<F>
+where
+    F: FnMut() -> io::Result<T>,
+{
+
+    type Value = T;
+    type Error = io::Error;
+
+    #[inline(always)]
+    fn into_result(mut self) -> Result<Self::Value, Self::Error> {
+        loop {
+            match (self.0)() {
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                result => return result,
+            }
+        }
+    }
+
+}
+
+
+/// Checks whether the child process has exited, without blocking.
+///
+/// The value is the [`ExitStatus`] of the exited process. The error is
+/// [`NotFinished::Running`] if the process is still running, or [`NotFinished::Failed`]
+/// with the [`io::Error`] of [`Child::try_wait`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use el_macro::{bind, adapters::Finished, error::NotFinished};
+/// # use std::{process::Command, thread, time::Duration};
+/// #
+/// let mut child = Command::new("sleep").arg("1").spawn().unwrap();
+///
+/// let status = loop {
+///     bind!(status = Finished(&mut child), or |err| match err {
+///         NotFinished::Running => thread::sleep(Duration::from_millis(100)),
+///         NotFinished::Failed(err) => panic!("{err}"),
+///     }, continue);
+///     break status;
+/// };
+/// assert!(status.success());
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct Finished<'a>(pub &'a mut Child);
+
+impl IntoResult for Finished<'_> {
+
+    type Value = ExitStatus;
+    type Error = NotFinished;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self.0.try_wait() {
+            Ok(Some(status)) => Ok(status),
+            Ok(None) => Err(NotFinished::Running),
+            Err(err) => Err(NotFinished::Failed(err)),
+        }
+    }
+
+}
@@ -0,0 +1,174 @@
+use std::{
+    borrow::Borrow,
+    collections::{btree_map, hash_map, BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+    ops::Range,
+};
+
+use crate::{bind::IntoResult, error::OutOfRange};
+
+
+/// Looks up the mutable reference to the value of the key in the map.
+///
+/// Implemented for [`HashMap`] and [`BTreeMap`], with the key borrowed
+/// in the same way as by their `get_mut` methods. The value is the mutable reference
+/// to the value of the key, borrowing the map for as long as the reference is used,
+/// and the error is the key, given back if the map doesn't contain it.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::GetMut};
+/// # use std::collections::HashMap;
+/// #
+/// let mut hits = HashMap::from([(String::from("/"), 41)]);
+///
+/// bind!(count = GetMut(&mut hits, "/"), or return);
+/// *count += 1;
+/// assert_eq!(hits["/"], 42);
+///
+/// // prints 'no hits of /about' and returns
+/// bind!(count = GetMut(&mut hits, "/about"), or |path| eprintln!("no hits of {path}"), return);
+/// unreachable!();
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct GetMut<'a, M, Q>(pub &'a mut M, pub Q);
+
+impl<'a, 'q, K, V, S, Q> IntoResult for GetMut<'a, HashMap<K, V, S>, &'q Q>
+where
+    K: Borrow<Q> + Hash + Eq,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+
+    type Value = &'a mut V;
+    type Error = &'q Q;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let Self(map, key) = self;
+        map.get_mut(key).ok_or(key)
+    }
+
+}
+
+impl<'a, 'q, K, V, Q> IntoResult for GetMut<'a, BTreeMap<K, V>, &'q Q>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+
+    type Value = &'a mut V;
+    type Error = &'q Q;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let Self(map, key) = self;
+        map.get_mut(key).ok_or(key)
+    }
+
+}
+
+
+/// Looks up the mutable reference to the element of the slice at the index.
+///
+/// The value is the mutable reference to the element, borrowing the slice for as long as
+/// the reference is used, and the error is the [`OutOfRange`] error holding the index
+/// and the range of the valid indices.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::SliceGetMut};
+/// #
+/// let mut scores = vec![4, 1];
+///
+/// bind!(last = SliceGetMut(&mut scores, 1), or return);
+/// *last += 1;
+/// assert_eq!(scores, [4, 2]);
+///
+/// // prints '2 is out of the range 0..2' and returns
+/// bind!(next = SliceGetMut(&mut scores, 2), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct SliceGetMut<'a, T>(pub &'a mut [T], pub usize);
+
+impl<'a, T> IntoResult for SliceGetMut<'a, T> {
+
+    type Value = &'a mut T;
+    type Error = OutOfRange<usize, Range<usize>>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let Self(slice, index) = self;
+        let bounds = 0..slice.len();
+        slice.get_mut(index).ok_or(OutOfRange { value: index, bounds })
+    }
+
+}
+
+
+/// Takes the occupied entry of the key in the map.
+///
+/// Implemented for [`HashMap`] and [`BTreeMap`]. The value is the occupied entry
+/// of the key, allowing to modify or remove the value in place, and the error is
+/// the vacant entry, allowing to insert the value if the map doesn't contain the key.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::Occupied};
+/// # use std::collections::{hash_map::VacantEntry, HashMap};
+/// #
+/// let mut sessions = HashMap::from([("alice", 1), ("bob", 0)]);
+///
+/// bind!(session = Occupied(&mut sessions, "bob"), or return);
+/// if *session.get() == 0 {
+///     session.remove();
+/// }
+/// assert!(!sessions.contains_key("bob"));
+///
+/// // inserts the session of `carol` and returns
+/// bind!(session = Occupied(&mut sessions, "carol"), or |vacant: VacantEntry<_, _>| { vacant.insert(1); }, return);
+/// unreachable!();
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct Occupied<'a, M, K>(pub &'a mut M, pub K);
+
+impl<'a, K, V, S> IntoResult for Occupied<'a, HashMap<K, V, S>, K>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+
+    type Value = hash_map::OccupiedEntry<'a, K, V>;
+    type Error = hash_map::VacantEntry<'a, K, V>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self.0.entry(self.1) {
+            hash_map::Entry::Occupied(entry) => Ok(entry),
+            hash_map::Entry::Vacant(entry) => Err(entry),
+        }
+    }
+
+}
+
+impl<'a, K: Ord, V> IntoResult for Occupied<'a, BTreeMap<K, V>, K> {
+
+    type Value = btree_map::OccupiedEntry<'a, K, V>;
+    type Error = btree_map::VacantEntry<'a, K, V>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self.0.entry(self.1) {
+            btree_map::Entry::Occupied(entry) => Ok(entry),
+            btree_map::Entry::Vacant(entry) => Err(entry),
+        }
+    }
+
+}
@@ -0,0 +1,70 @@
+//! Adapters implementing [`IntoResult`](crate::bind::IntoResult) for values that
+//! can't or shouldn't implement it directly
+
+
+mod all_ok;
+#[cfg(feature = "async-lock")]
+mod async_lock;
+#[cfg(feature = "nightly-try")]
+mod bindable;
+mod chars;
+mod checked;
+#[cfg(feature = "clap")]
+mod clap;
+#[cfg(feature = "crossbeam")]
+mod crossbeam;
+mod downcast;
+#[cfg(feature = "either")]
+mod either;
+#[cfg(feature = "futures")]
+mod futures;
+#[cfg(feature = "http")]
+mod http;
+mod io;
+mod lookup;
+mod option;
+mod path;
+mod predicate;
+mod query;
+mod rc;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+mod sync;
+#[cfg(feature = "nightly-try")]
+mod try_bridge;
+
+
+pub use all_ok::AllOk;
+#[cfg(feature = "async-lock")]
+pub use async_lock::{TryAcquire, TryLockAsync, TryReadAsync, TryWriteAsync};
+#[cfg(feature = "nightly-try")]
+pub use bindable::Bindable;
+pub use chars::{FromDigit, FromU32, ToDigit};
+pub use checked::{CheckedAdd, CheckedArithmetic, CheckedDiv, CheckedMul, CheckedSub};
+#[cfg(feature = "clap")]
+pub use clap::{ArgMany, ArgOne};
+#[cfg(feature = "crossbeam")]
+pub use crossbeam::{RecvTimeout, SelectedRecv, TryRecv};
+pub use downcast::Downcast;
+#[cfg(feature = "either")]
+pub use either::LeftValue;
+#[cfg(feature = "futures")]
+pub use futures::{TryNext, TryNowOrNever, TryRecvOneshot};
+#[cfg(feature = "http")]
+pub use http::SuccessOf;
+pub use io::{Finished, InterruptedRetried, NotFoundAsNone};
+pub use lookup::{GetMut, Occupied, SliceGetMut};
+pub use option::Strict;
+pub use path::{ExistingDir, ExistingFile, ReadablePath};
+pub use predicate::{Bounded, ErrIf, OkIf, Validated};
+pub use query::QueryOne;
+pub use rc::{UniqueArc, UniqueRc};
+#[cfg(feature = "rusqlite")]
+pub use rusqlite::ColumnAs;
+#[cfg(feature = "sqlx")]
+pub use sqlx::TryGetAs;
+pub use sync::{FailOnPoison, RecoverPoison, TryLock, TryRead, TryWrite};
+#[cfg(feature = "nightly-try")]
+pub use try_bridge::{ResidualError, TryBridge};
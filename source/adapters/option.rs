@@ -0,0 +1,40 @@
+use crate::{bind::IntoResult, error::NoneError};
+
+
+/// Gives an [`Option`] a meaningful error naming the type of the expected value.
+///
+/// The value is the value of the [`Option`], the error is the [`NoneError`]
+/// instead of `()`, so it can be logged or boxed into `dyn Error`.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::Strict};
+/// # use std::error::Error;
+/// #
+/// fn first_word(s: &str) -> Result<&str, Box<dyn Error>> {
+///     bind!(word = Strict(s.split_whitespace().next()), or throw);
+///     Ok(word)
+/// }
+///
+/// assert_eq!(first_word("el macro").unwrap(), "el");
+/// assert_eq!(
+///     first_word(" ").unwrap_err().to_string(),
+///     "expected a value of type `&str`, found none",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct Strict<T>(pub Option<T>);
+
+impl<T> IntoResult for Strict<T> {
+
+    type Value = T;
+    type Error = NoneError;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.ok_or_else(NoneError::new::<T>)
+    }
+
+}
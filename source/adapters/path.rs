@@ -0,0 +1,126 @@
+use std::{fs, io, path::{Path, PathBuf}};
+
+use crate::{bind::IntoResult, error::PathError};
+
+
+/// Requires the path to point to an existing file.
+///
+/// The value is the [canonicalized](fs::canonicalize) path, the error is the [`PathError`]
+/// holding the path and the [`io::Error`] of the failed check.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::ExistingFile};
+/// #
+/// bind!(manifest = ExistingFile(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")), or return);
+/// assert!(manifest.is_absolute());
+///
+/// // prints 'nonexistent.toml: No such file or directory (os error 2)' and returns
+/// bind!(config = ExistingFile("nonexistent.toml"), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct ExistingFile<P>(pub P);
+
+impl<P: AsRef<Path>> IntoResult for ExistingFile<P> {
+
+    type Value = PathBuf;
+    type Error = PathError;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        check(self.0.as_ref(), |path| {
+            if fs::metadata(path)?.is_file() {
+                Ok(())
+            } else {
+                Err(io::Error::new(io::ErrorKind::InvalidInput, "not a file"))
+            }
+        })
+    }
+
+}
+
+
+/// Requires the path to point to an existing directory.
+///
+/// The value is the [canonicalized](fs::canonicalize) path, the error is the [`PathError`]
+/// holding the path and the [`io::Error`] of the failed check.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::ExistingDir};
+/// #
+/// bind!(root = ExistingDir(env!("CARGO_MANIFEST_DIR")), or return);
+/// assert!(root.join("Cargo.toml").is_file());
+///
+/// // prints '…/Cargo.toml: not a directory' and returns
+/// bind!(out = ExistingDir(root.join("Cargo.toml")), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct ExistingDir<P>(pub P);
+
+impl<P: AsRef<Path>> IntoResult for ExistingDir<P> {
+
+    type Value = PathBuf;
+    type Error = PathError;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        check(self.0.as_ref(), |path| {
+            if fs::metadata(path)?.is_dir() {
+                Ok(())
+            } else {
+                Err(io::Error::new(io::ErrorKind::InvalidInput, "not a directory"))
+            }
+        })
+    }
+
+}
+
+
+/// Requires the path to point to a file that can be opened or a directory that can be listed.
+///
+/// The value is the [canonicalized](fs::canonicalize) path, the error is the [`PathError`]
+/// holding the path and the [`io::Error`] of the failed check.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::ReadablePath};
+/// #
+/// bind!(input = ReadablePath(env!("CARGO_MANIFEST_DIR")), or return);
+/// assert!(input.is_absolute());
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct ReadablePath<P>(pub P);
+
+impl<P: AsRef<Path>> IntoResult for ReadablePath<P> {
+
+    type Value = PathBuf;
+    type Error = PathError;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        check(self.0.as_ref(), |path| {
+            if fs::metadata(path)?.is_dir() {
+                fs::read_dir(path).map(drop)
+            } else {
+                fs::File::open(path).map(drop)
+            }
+        })
+    }
+
+}
+
+
+fn check(path: &Path, test: impl FnOnce(&Path) -> io::Result<()>) -> Result<PathBuf, PathError> {
+    fs::canonicalize(path)
+        .and_then(|canonical| test(&canonical).map(|()| canonical))
+        .map_err(|error| PathError { path: path.to_path_buf(), error })
+}
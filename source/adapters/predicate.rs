@@ -0,0 +1,205 @@
+use std::ops::RangeBounds;
+
+use crate::{
+    bind::IntoResult,
+    error::{Invalid, OutOfRange},
+    validate::Predicate,
+};
+
+
+/// Accepts the value if it satisfies the predicate.
+///
+/// The value is the provided value if the predicate returns `true` for it.
+/// Otherwise, the value is returned as the error.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::OkIf};
+/// #
+/// let even = |n: &i32| n % 2 == 0;
+///
+/// bind!(x = OkIf(42, even), or return);
+/// assert_eq!(x, 42);
+///
+/// // prints 'odd: 43' and returns
+/// bind!(x = OkIf(43, even), or |n| eprintln!("odd: {n}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct OkIf<T, P>(pub T, pub P);
+
+impl<T, P> IntoResult for OkIf<T, P>
+where
+    P: FnOnce(&T) -> bool,
+{
+
+    type Value = T;
+    type Error = T;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let Self(value, predicate) = self;
+        match predicate(&value) {
+            true => Ok(value),
+            false => Err(value),
+        }
+    }
+
+}
+
+
+/// Rejects the value if it satisfies the predicate.
+///
+/// The error is made by the provided function of the value if the predicate
+/// returns `true` for it. Otherwise, the value is the provided value.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::ErrIf};
+/// #
+/// let odd = |n: &i32| n % 2 != 0;
+/// let err = |n| format!("{n} is odd");
+///
+/// bind!(x = ErrIf(42, odd, err), or return);
+/// assert_eq!(x, 42);
+///
+/// // prints '43 is odd' and returns
+/// bind!(x = ErrIf(43, odd, err), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct ErrIf<T, P, F>(pub T, pub P, pub F);
+
+impl<T, P, F, E> IntoResult for ErrIf<T, P, F>
+where
+    P: FnOnce(&T) -> bool,
+    F: FnOnce(T) -> E,
+{
+
+    type Value = T;
+    type Error = E;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let Self(value, predicate, err) = self;
+        match predicate(&value) {
+            true => Err(err(value)),
+            false => Ok(value),
+        }
+    }
+
+}
+
+
+/// Accepts the value if it passes the reusable check.
+///
+/// The value is the provided value if the [`Predicate`] holds for it. Otherwise,
+/// the error is the [`Invalid`] error holding the value and the name of the predicate.
+/// Unlike [`OkIf`], turns the named domain checks into the sources for
+/// the [`crate::bind!`] macro.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::Validated, error::Invalid, validate::Predicate};
+/// #
+/// struct Port;
+///
+/// impl Predicate<u32> for Port {
+///
+///     fn test(&self, value: &u32) -> bool {
+///         (1..=65535).contains(value)
+///     }
+///
+///     fn name(&self) -> &'static str {
+///         "port"
+///     }
+///
+/// }
+///
+/// bind!(port = Validated(8080, Port), or return);
+/// assert_eq!(port, 8080);
+///
+/// fn is_even(n: &u32) -> bool {
+///     n % 2 == 0
+/// }
+///
+/// bind!(n = Validated(42, is_even), or return);
+/// assert_eq!(n, 42);
+///
+/// // prints '70000 fails the check `port`' and returns
+/// bind!(port = Validated(70000, Port), or |err: Invalid<u32>| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct Validated<T, P>(pub T, pub P);
+
+impl<T, P> IntoResult for Validated<T, P>
+where
+    P: Predicate<T>,
+{
+
+    type Value = T;
+    type Error = Invalid<T>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let Self(value, predicate) = self;
+        match predicate.test(&value) {
+            true => Ok(value),
+            false => Err(Invalid { value, check: predicate.name() }),
+        }
+    }
+
+}
+
+
+/// Accepts the value if it's within the range.
+///
+/// The value is the provided value if the range [contains](RangeBounds::contains) it.
+/// Otherwise, the error is the [`OutOfRange`] error holding the value and the range.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::Bounded, error::OutOfRange};
+/// # use std::ops::RangeInclusive;
+/// #
+/// bind!(port = Bounded(8080, 1..=65535), or return);
+/// assert_eq!(port, 8080);
+///
+/// bind!(percent = Bounded(42.5, 0.0..=100.0), or return);
+/// assert_eq!(percent, 42.5);
+///
+/// // prints '0 is out of the range 1..=65535' and returns
+/// bind!(port = Bounded(0, 1..=65535), or |err: OutOfRange<i32, RangeInclusive<i32>>| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct Bounded<T, R>(pub T, pub R);
+
+impl<T, R> IntoResult for Bounded<T, R>
+where
+    T: PartialOrd,
+    R: RangeBounds<T>,
+{
+
+    type Value = T;
+    type Error = OutOfRange<T, R>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let Self(value, bounds) = self;
+        match bounds.contains(&value) {
+            true => Ok(value),
+            false => Err(OutOfRange { value, bounds }),
+        }
+    }
+
+}
@@ -0,0 +1,47 @@
+use crate::{bind::IntoResult, error::QueryError};
+
+
+/// Requires the optional query result to have a row.
+///
+/// Takes the outcome of a query that returns at most one row, such as the one of
+/// `rusqlite`'s `OptionalExtension::optional` or `sqlx`'s `fetch_optional`, along with
+/// the query. The value is the row, the error is the [`QueryError`] naming the query,
+/// with the error of the query or without one if the query returned no rows.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::QueryOne};
+/// #
+/// fn fetch_optional(id: u32) -> Result<Option<&'static str>, String> {
+///     Ok((id == 1).then_some("alice"))
+/// }
+///
+/// const QUERY: &str = "SELECT name FROM users WHERE id = ?";
+///
+/// bind!(name = QueryOne(fetch_optional(1), QUERY), or return);
+/// assert_eq!(name, "alice");
+///
+/// // prints 'query `SELECT name FROM users WHERE id = ?` returned no rows' and returns
+/// bind!(name = QueryOne(fetch_optional(2), QUERY), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct QueryOne<'a, T, E>(pub Result<Option<T>, E>, pub &'a str);
+
+impl<T, E> IntoResult for QueryOne<'_, T, E> {
+
+    type Value = T;
+    type Error = QueryError<E>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self.0 {
+            Ok(Some(row)) => Ok(row),
+            Ok(None) => Err(QueryError { query: self.1.to_owned(), error: None }),
+            Err(error) => Err(QueryError { query: self.1.to_owned(), error: Some(error) }),
+        }
+    }
+
+}
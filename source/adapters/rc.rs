@@ -0,0 +1,85 @@
+use std::{rc::Rc, sync::Arc};
+
+use crate::bind::IntoResult;
+
+
+/// Takes the value out of the [`Rc`] if it's the only strong reference.
+///
+/// The value is the inner value, the error is the original [`Rc`], given back
+/// if there are other strong references, as with [`Rc::try_unwrap`].
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::UniqueRc};
+/// # use std::rc::Rc;
+/// #
+/// let shared = Rc::new(vec![4, 2]);
+/// let _other = Rc::clone(&shared);
+///
+/// // gets the `Rc` back and returns
+/// bind!(owned = UniqueRc(shared), or |shared: Rc<_>| assert_eq!(Rc::strong_count(&shared), 2), return);
+/// unreachable!();
+/// ```
+///
+/// ```
+/// # use el_macro::{bind, adapters::UniqueRc};
+/// # use std::rc::Rc;
+/// #
+/// bind!(mut owned = UniqueRc(Rc::new(vec![4])), or return);
+/// owned.push(2);
+/// assert_eq!(owned, [4, 2]);
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct UniqueRc<T>(pub Rc<T>);
+
+impl<T> IntoResult for UniqueRc<T> {
+
+    type Value = T;
+    type Error = Rc<T>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        Rc::try_unwrap(self.0)
+    }
+
+}
+
+
+/// Takes the value out of the [`Arc`] if it's the only strong reference.
+///
+/// The value is the inner value, the error is the original [`Arc`], given back
+/// if there are other strong references, as with [`Arc::try_unwrap`].
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::UniqueArc};
+/// # use std::{sync::Arc, thread};
+/// #
+/// let shared = Arc::new(vec![4, 2]);
+/// let worker = {
+///     let shared = Arc::clone(&shared);
+///     thread::spawn(move || shared.len())
+/// };
+/// assert_eq!(worker.join().unwrap(), 2);
+///
+/// bind!(owned = UniqueArc(shared), or return);
+/// assert_eq!(owned, [4, 2]);
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct UniqueArc<T>(pub Arc<T>);
+
+impl<T> IntoResult for UniqueArc<T> {
+
+    type Value = T;
+    type Error = Arc<T>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        Arc::try_unwrap(self.0)
+    }
+
+}
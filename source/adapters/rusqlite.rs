@@ -0,0 +1,60 @@
+use std::marker::PhantomData;
+
+use rusqlite::{types::FromSql, Row};
+
+use crate::{bind::IntoResult, error::ColumnError};
+
+
+/// Gets the value of the `rusqlite` [`Row`] column converted to `T`.
+///
+/// The value is the converted value of the column, the error is the [`ColumnError`]
+/// naming the column, with the [`rusqlite::Error`] of [`Row::get`].
+/// Created with the [`ColumnAs()`] function.
+///
+/// Available with the `rusqlite` feature.
+#[must_use]
+pub struct ColumnAs<'a, 'r, T> {
+    row: &'a Row<'r>,
+    column: &'a str,
+    target: PhantomData<fn() -> T>,
+}
+
+/// Creates the [`ColumnAs`] adapter getting the value of the column converted to `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::ColumnAs};
+/// # use rusqlite::Connection;
+/// #
+/// let conn = Connection::open_in_memory().unwrap();
+/// let mut stmt = conn.prepare("SELECT 'alice' AS name, NULL AS age").unwrap();
+/// let mut rows = stmt.query([]).unwrap();
+///
+/// while let Some(row) = rows.next().unwrap() {
+///     bind!(name = ColumnAs::<String>(row, "name"), or continue);
+///     assert_eq!(name, "alice");
+///
+///     // prints 'column `age`: Invalid column type Null at index: 1, name: age' and continues
+///     bind!(age = ColumnAs::<u32>(row, "age"), or |err| eprintln!("{err}"), continue);
+///     unreachable!();
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[inline(always)]
+pub fn ColumnAs<'a, 'r, T>(row: &'a Row<'r>, column: &'a str) -> ColumnAs<'a, 'r, T> {
+    ColumnAs { row, column, target: PhantomData }
+}
+
+impl<T: FromSql> IntoResult for ColumnAs<'_, '_, T> {
+
+    type Value = T;
+    type Error = ColumnError<rusqlite::Error>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.row.get(self.column)
+            .map_err(|error| ColumnError { column: self.column.to_owned(), error })
+    }
+
+}
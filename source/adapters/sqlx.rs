@@ -0,0 +1,62 @@
+use std::marker::PhantomData;
+
+use sqlx::{ColumnIndex, Decode, Row, Type};
+
+use crate::{bind::IntoResult, error::ColumnError};
+
+
+/// Gets the value of the `sqlx` [`Row`] column decoded as `T`.
+///
+/// The value is the decoded value of the column, the error is the [`ColumnError`]
+/// naming the column, with the [`sqlx::Error`] of [`Row::try_get`].
+/// Created with the [`TryGetAs()`] function. The `sqlx` counterpart to
+/// the `ColumnAs` adapter of the `rusqlite` feature.
+///
+/// Available with the `sqlx` feature.
+#[must_use]
+pub struct TryGetAs<'a, R, T> {
+    row: &'a R,
+    column: &'a str,
+    target: PhantomData<fn() -> T>,
+}
+
+/// Creates the [`TryGetAs`] adapter getting the value of the column decoded as `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryGetAs};
+/// # use sqlx::{ColumnIndex, Decode, Row, Type};
+/// #
+/// fn user_name<R: Row>(row: &R) -> Option<String>
+/// where
+///     for<'r> String: Decode<'r, R::Database> + Type<R::Database>,
+///     for<'r> &'r str: ColumnIndex<R>,
+/// {
+///     bind!(name = TryGetAs::<String, _>(row, "name"), or |err| eprintln!("{err}"), none);
+///     Some(name)
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[inline(always)]
+pub fn TryGetAs<'a, T, R: Row>(row: &'a R, column: &'a str) -> TryGetAs<'a, R, T> {
+    TryGetAs { row, column, target: PhantomData }
+}
+
+impl<'a, R, T> IntoResult for TryGetAs<'a, R, T>
+where
+    R: Row,
+    T: Decode<'a, R::Database> + Type<R::Database>,
+    &'a str: ColumnIndex<R>,
+{
+
+    type Value = T;
+    type Error = ColumnError<sqlx::Error>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.row.try_get(self.column)
+            .map_err(|error| ColumnError { column: self.column.to_owned(), error })
+    }
+
+}
@@ -0,0 +1,190 @@
+use std::{
+    convert::Infallible,
+    sync::{
+        Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError,
+    },
+};
+
+use crate::bind::IntoResult;
+
+
+/// Locks the [`Mutex`], recovering the guard if the mutex is poisoned.
+///
+/// The value is the guard, which is recovered from the [`PoisonError`] if another thread
+/// panicked while holding the lock. Suits the code that treats poisoning as recoverable.
+/// Never fails, so the `or` clause of the [`crate::bind!`] macro can be omitted.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::RecoverPoison};
+/// # use std::{panic, sync::Mutex};
+/// #
+/// let counter = Mutex::new(41);
+/// let _ = panic::catch_unwind(|| {
+///     let _guard = counter.lock();
+///     panic!("poisoning the mutex");
+/// });
+/// assert!(counter.is_poisoned());
+///
+/// bind!(mut guard = RecoverPoison(&counter));
+/// *guard += 1;
+/// assert_eq!(*guard, 42);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct RecoverPoison<'a, T>(pub &'a Mutex<T>);
+
+impl<'a, T> IntoResult for RecoverPoison<'a, T> {
+
+    type Value = MutexGuard<'a, T>;
+    type Error = Infallible;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        Ok(self.0.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+}
+
+
+/// Locks the [`Mutex`], failing if the mutex is poisoned.
+///
+/// The value is the guard, the error is the [`PoisonError`]. Same as the [`IntoResult`]
+/// implementation for `&Mutex`, for stating the intent explicitly next to [`RecoverPoison`].
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::FailOnPoison};
+/// # use std::sync::Mutex;
+/// #
+/// let counter = Mutex::new(42);
+///
+/// bind!(guard = FailOnPoison(&counter), or return);
+/// assert_eq!(*guard, 42);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct FailOnPoison<'a, T>(pub &'a Mutex<T>);
+
+impl<'a, T> IntoResult for FailOnPoison<'a, T> {
+
+    type Value = MutexGuard<'a, T>;
+    type Error = PoisonError<Self::Value>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.lock()
+    }
+
+}
+
+
+/// Locks the [`Mutex`] only if it's immediately available.
+///
+/// The value is the guard, the error is the [`TryLockError`], which is
+/// [`WouldBlock`](TryLockError::WouldBlock) if the lock is held elsewhere,
+/// or [`Poisoned`](TryLockError::Poisoned) if the mutex is poisoned. Unlike
+/// the [`IntoResult`] implementation for `&Mutex`, never blocks.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryLock};
+/// # use std::sync::Mutex;
+/// #
+/// let counter = Mutex::new(42);
+///
+/// for _ in 0..2 {
+///     bind!(guard = TryLock(&counter), or continue);
+///     assert_eq!(*guard, 42);
+/// }
+///
+/// let _held = counter.lock().unwrap();
+/// // prints 'try_lock failed because the operation would block' and returns
+/// bind!(guard = TryLock(&counter), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct TryLock<'a, T>(pub &'a Mutex<T>);
+
+impl<'a, T> IntoResult for TryLock<'a, T> {
+
+    type Value = MutexGuard<'a, T>;
+    type Error = TryLockError<Self::Value>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.try_lock()
+    }
+
+}
+
+
+/// Locks the [`RwLock`] with shared read access only if it's immediately available.
+///
+/// The value is the read guard, the error is the [`TryLockError`], same as for [`TryLock`].
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryRead};
+/// # use std::sync::RwLock;
+/// #
+/// let config = RwLock::new("debug");
+///
+/// bind!(first = TryRead(&config), or return);
+/// bind!(second = TryRead(&config), or return);
+/// assert_eq!(*first, *second);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct TryRead<'a, T>(pub &'a RwLock<T>);
+
+impl<'a, T> IntoResult for TryRead<'a, T> {
+
+    type Value = RwLockReadGuard<'a, T>;
+    type Error = TryLockError<Self::Value>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.try_read()
+    }
+
+}
+
+
+/// Locks the [`RwLock`] with exclusive write access only if it's immediately available.
+///
+/// The value is the write guard, the error is the [`TryLockError`], same as for [`TryLock`].
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::{TryRead, TryWrite}};
+/// # use std::sync::RwLock;
+/// #
+/// let config = RwLock::new("debug");
+///
+/// bind!(reader = TryRead(&config), or return);
+/// // prints 'try_lock failed because the operation would block' and returns
+/// bind!(writer = TryWrite(&config), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct TryWrite<'a, T>(pub &'a RwLock<T>);
+
+impl<'a, T> IntoResult for TryWrite<'a, T> {
+
+    type Value = RwLockWriteGuard<'a, T>;
+    type Error = TryLockError<Self::Value>;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.0.try_write()
+    }
+
+}
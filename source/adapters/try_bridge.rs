@@ -0,0 +1,106 @@
+use std::{
+    convert::Infallible,
+    ops::{ControlFlow, Try},
+};
+
+use crate::bind::IntoResult;
+
+
+/// Extracts the error from the [`Try::Residual`] of a [`Try`] type.
+///
+/// Implemented for the residuals of [`Result`], [`Option`] and [`ControlFlow`],
+/// which also covers the [`Try`] types reusing those, such as [`Poll`](std::task::Poll).
+pub trait ResidualError {
+
+    /// Type of the error held by the residual.
+    type Error;
+
+    /// Extracts the error held by the residual.
+    fn into_error(self) -> Self::Error;
+
+}
+
+impl<E> ResidualError for Result<Infallible, E> {
+
+    type Error = E;
+
+    fn into_error(self) -> Self::Error {
+        match self {
+            Ok(never) => match never { },
+            Err(err) => err,
+        }
+    }
+
+}
+
+impl ResidualError for Option<Infallible> {
+
+    type Error = ();
+
+    fn into_error(self) -> Self::Error { }
+
+}
+
+impl<B> ResidualError for ControlFlow<B, Infallible> {
+
+    type Error = B;
+
+    fn into_error(self) -> Self::Error {
+        match self {
+            ControlFlow::Continue(never) => match never { },
+            ControlFlow::Break(err) => err,
+        }
+    }
+
+}
+
+
+/// Bridges any [`Try`] type to [`IntoResult`].
+///
+/// The value is the [`Try::Output`], the error is extracted from the [`Try::Residual`]
+/// by [`ResidualError`]. A blanket [`IntoResult`] implementation for all the [`Try`] types
+/// is not possible, as it would overlap with the implementations for [`Result`] and [`Option`].
+///
+/// Available with the `nightly-try` feature, which requires a nightly compiler.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, adapters::TryBridge};
+/// # use std::{ops::ControlFlow, task::Poll};
+/// #
+/// let ready = Poll::Ready(Ok::<_, &str>(42));
+/// bind!(x = TryBridge(ready), or return);
+/// assert_eq!(x, Poll::Ready(42));
+///
+/// let flow = ControlFlow::<&str, i32>::Continue(42);
+/// bind!(x = TryBridge(flow), or return);
+/// assert_eq!(x, 42);
+///
+/// // prints 'stop' and returns
+/// let flow = ControlFlow::<&str, i32>::Break("stop");
+/// bind!(x = TryBridge(flow), or |reason| eprintln!("{reason}"), return);
+/// unreachable!();
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct TryBridge<T>(pub T);
+
+impl<T> IntoResult for TryBridge<T>
+where
+    T: Try,
+    T::Residual: ResidualError,
+{
+
+    type Value = T::Output;
+    type Error = <T::Residual as ResidualError>::Error;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self.0.branch() {
+            ControlFlow::Continue(value) => Ok(value),
+            ControlFlow::Break(residual) => Err(residual.into_error()),
+        }
+    }
+
+}
@@ -0,0 +1,58 @@
+//! The [`crate::attempt!`] macro
+
+
+/// Evaluates the block as a separate function body
+///
+/// Emulates the `try` blocks on stable Rust. The statements of the provided block
+/// are evaluated inside an immediately invoked closure, so the `?` operator and `return`
+/// used within it exit the block rather than the enclosing function. Yields the value
+/// the block evaluates to, which makes it possible to isolate a group of fallible steps
+/// from the return type of the enclosing function and feed the outcome into [`crate::bind!`].
+///
+/// Unlike the `try` blocks, the tail expression of the block is not wrapped automatically,
+/// so the block must evaluate to [`Result`] or [`Option`] itself, as a function body would.
+/// `break` and `continue` can't reach the loops outside of the block.
+///
+/// # Syntax
+///
+/// ```text
+/// attempt! { <statements> }
+/// attempt!(-> <type> { <statements> })
+/// ```
+///
+/// - `<statements>` — body of the block.
+/// - `<type>` — optional type of the block value, for cases when it can't be inferred.
+///
+/// # Examples
+///
+/// Basic usage:
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::{attempt, bind};
+/// #
+/// let sum = attempt! {
+///     let a = "40".parse::<i32>()?;
+///     let b = "2".parse::<i32>()?;
+///     Ok::<_, std::num::ParseIntError>(a + b)
+/// };
+/// assert_eq!(sum, Ok(42));
+///
+/// bind!(x = attempt!(-> Option<i32> {
+///     let a = Some(40)?;
+///     let b = None::<i32>?;
+///     Some(a + b)
+/// }), or return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! attempt {
+
+    (-> $t: ty { $($s: tt)* }) => {
+        $crate::private::call(|| -> $t { $($s)* })
+    };
+
+    ($($s: tt)*) => {
+        $crate::private::call(|| { $($s)* })
+    };
+
+}
@@ -0,0 +1,55 @@
+/// Receives the errors captured by the [`crate::bind!`] macro.
+///
+/// Used by the [`crate::bind!`] macro if the error handler is `capture <sink>`.
+/// Then the error is passed to the sink instead of a handler, so it can be reported after
+/// the flow control expression, such as after a loop that skips the failed items.
+/// Implemented for [`Option`], which keeps the last error, for [`Vec`], which keeps
+/// all the errors, and for mutable references to the sinks.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::bind;
+/// #
+/// let mut last_err = None;
+/// let mut sum = 0;
+///
+/// for s in ["4", "x", "2", "y"] {
+///     bind!(n = s.parse::<i32>(), or capture last_err, continue);
+///     sum += n;
+/// }
+///
+/// assert_eq!(sum, 6);
+/// assert_eq!(last_err.unwrap().to_string(), "invalid digit found in string");
+/// ```
+pub trait ErrorSink<E> {
+
+    /// Takes the error.
+    fn capture(&mut self, err: E);
+
+}
+
+impl<E> ErrorSink<E> for Option<E> {
+
+    fn capture(&mut self, err: E) {
+        *self = Some(err);
+    }
+
+}
+
+impl<E> ErrorSink<E> for Vec<E> {
+
+    fn capture(&mut self, err: E) {
+        self.push(err);
+    }
+
+}
+
+impl<E, S: ErrorSink<E> + ?Sized> ErrorSink<E> for &mut S {
+
+    fn capture(&mut self, err: E) {
+        S::capture(self, err);
+    }
+
+}
@@ -4,7 +4,16 @@
 /// whether to create a variable and bind it to the value, or to call the optional error handler
 /// and evaluate the execution flow control block.
 ///
-/// Implemented by default for [`Result`] and [`Option`], with `()` as `Error` for the latter.
+/// Implemented by default for [`Result`], [`Option`] and `&`[`Mutex`](std::sync::Mutex),
+/// with `()` as `Error` for [`Option`]. Each of those can be opted out of by disabling
+/// the default features and enabling only the needed ones of `impl-result`, `impl-option`
+/// and `impl-mutex`, such as to keep the blocking lock acquisition out of async code.
+/// Wrap the [`Option`] into the [`Strict`](crate::adapters::Strict) adapter
+/// for a meaningful error instead of `()`.
+/// Also implemented for `bool`, with `()` as `Value` and [`False`](crate::error::False)
+/// as `Error`.
+/// With the `either` feature, also implemented for `either::Either`, with `Right` as `Value`
+/// and `Left` as `Error`.
 ///
 /// For the usage example, refer to the [`crate::bind!`] macro documentation, which includes
 /// an example of using it with user-defined types.
@@ -22,11 +31,13 @@ pub trait IntoResult {
 }
 
 
+#[cfg(feature = "impl-option")]
 impl<T> IntoResult for Option<T> {
 
     type Value = T;
     type Error = ();
 
+    #[inline(always)]
     fn into_result(self) -> Result<Self::Value, Self::Error> {
         self.ok_or(())
     }
@@ -34,11 +45,13 @@ impl<T> IntoResult for Option<T> {
 }
 
 
+#[cfg(feature = "impl-result")]
 impl<T, E> IntoResult for Result<T, E> {
 
     type Value = T;
     type Error = E;
 
+    #[inline(always)]
     fn into_result(self) -> Result<Self::Value, Self::Error> {
         self
     }
@@ -46,13 +59,45 @@ impl<T, E> IntoResult for Result<T, E> {
 }
 
 
+#[cfg(feature = "impl-mutex")]
 impl<'a, T> IntoResult for &'a std::sync::Mutex<T> {
 
     type Value = std::sync::MutexGuard<'a, T>;
     type Error = std::sync::PoisonError<Self::Value>;
 
+    #[inline(always)]
     fn into_result(self) -> Result<Self::Value, Self::Error> {
         self.lock()
     }
 
 }
+
+
+impl IntoResult for bool {
+
+    type Value = ();
+    type Error = crate::error::False;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.then_some(()).ok_or(crate::error::False)
+    }
+
+}
+
+
+#[cfg(feature = "either")]
+impl<L, R> IntoResult for either::Either<L, R> {
+
+    type Value = R;
+    type Error = L;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        match self {
+            either::Either::Left(err) => Err(err),
+            either::Either::Right(value) => Ok(value),
+        }
+    }
+
+}
@@ -0,0 +1,179 @@
+use std::iter::FusedIterator;
+
+use super::IntoResult;
+
+
+/// Extends iterators over [`IntoResult`] items with methods that unwrap the items.
+///
+/// Implemented for all iterators. The methods are available if the items implement [`IntoResult`].
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::bind::BindIterExt;
+/// #
+/// let items = || ["4", "x", "2"].into_iter().map(str::parse::<i32>);
+///
+/// assert_eq!(items().ok_values().collect::<Vec<_>>(), [4, 2]);
+/// assert_eq!(items().take_until_err().collect::<Vec<_>>(), [4]);
+/// assert_eq!(items().first_value(), Some(4));
+///
+/// let (values, errors) = items().partition_outcomes();
+/// assert_eq!(values, [4, 2]);
+/// assert_eq!(errors.len(), 1);
+///
+/// let mut values = std::collections::BTreeSet::from([0]);
+/// let mut errors = Vec::new();
+/// items().partition_outcomes_into(&mut values, &mut errors);
+/// items().partition_outcomes_into(&mut values, &mut errors);
+/// assert_eq!(values.into_iter().collect::<Vec<_>>(), [0, 2, 4]);
+/// assert_eq!(errors.len(), 2);
+/// ```
+pub trait BindIterExt: Iterator + Sized {
+
+    /// Yields the unwrapped values of the items, skipping the items that can't be unwrapped.
+    fn ok_values(self) -> OkValues<Self>
+    where
+        Self::Item: IntoResult,
+    {
+        OkValues { iter: self }
+    }
+
+    /// Yields the unwrapped values of the items until the first item that can't be unwrapped.
+    ///
+    /// The error of that item can be retrieved from the returned iterator.
+    fn take_until_err(self) -> TakeUntilErr<Self, <Self::Item as IntoResult>::Error>
+    where
+        Self::Item: IntoResult,
+    {
+        TakeUntilErr { iter: self, error: None }
+    }
+
+    /// Splits the items into the unwrapped values and the errors in one pass,
+    /// preserving their order.
+    #[allow(clippy::type_complexity)]
+    fn partition_outcomes(
+        self,
+    ) -> (Vec<<Self::Item as IntoResult>::Value>, Vec<<Self::Item as IntoResult>::Error>)
+    where
+        Self::Item: IntoResult,
+    {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        self.partition_outcomes_into(&mut values, &mut errors);
+        (values, errors)
+    }
+
+    /// Splits the items into the unwrapped values and the errors in one pass, preserving
+    /// their order, and appends them to the provided collections.
+    ///
+    /// Lets the collections be reused across the batches of items, or be other than [`Vec`].
+    fn partition_outcomes_into<V, E>(self, values: &mut V, errors: &mut E)
+    where
+        Self::Item: IntoResult,
+        V: Extend<<Self::Item as IntoResult>::Value>,
+        E: Extend<<Self::Item as IntoResult>::Error>,
+    {
+        for item in self {
+            match item.into_result() {
+                Ok(value) => values.extend(Some(value)),
+                Err(err) => errors.extend(Some(err)),
+            }
+        }
+    }
+
+    /// Returns the first unwrapped value, skipping the items that can't be unwrapped.
+    fn first_value(mut self) -> Option<<Self::Item as IntoResult>::Value>
+    where
+        Self::Item: IntoResult,
+    {
+        self.find_map(|item| item.into_result().ok())
+    }
+
+}
+
+impl<I: Iterator> BindIterExt for I { }
+
+
+/// Iterator returned by [`BindIterExt::ok_values`].
+#[derive(Clone, Debug)]
+pub struct OkValues<I> {
+    iter: I,
+}
+
+impl<I> Iterator for OkValues<I>
+where
+    I: Iterator,
+    I::Item: IntoResult,
+{
+
+    type Item = <I::Item as IntoResult>::Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find_map(|item| item.into_result().ok())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+
+}
+
+impl<I> FusedIterator for OkValues<I>
+where
+    I: FusedIterator,
+    I::Item: IntoResult,
+{ }
+
+
+/// Iterator returned by [`BindIterExt::take_until_err`].
+#[derive(Clone, Debug)]
+pub struct TakeUntilErr<I, E> {
+    iter: I,
+    error: Option<E>,
+}
+
+impl<I, E> TakeUntilErr<I, E> {
+
+    /// Returns the error of the item that stopped the iteration, if any.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// Returns the error of the item that stopped the iteration, if any.
+    pub fn into_error(self) -> Option<E> {
+        self.error
+    }
+
+}
+
+impl<I, E> Iterator for TakeUntilErr<I, E>
+where
+    I: Iterator,
+    I::Item: IntoResult<Error = E>,
+{
+
+    type Item = <I::Item as IntoResult>::Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+        match self.iter.next()?.into_result() {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.error = Some(err);
+                None
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.error {
+            Some(_) => (0, Some(0)),
+            None => (0, self.iter.size_hint().1),
+        }
+    }
+
+}
@@ -1,13 +1,19 @@
 //! The [`crate::bind!`] macro and related [`IntoResult`] trait
 
 
+mod error_sink;
 mod into_result;
+mod iter_ext;
+mod try_unwrap;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "impl-option", feature = "impl-result"))]
 mod test;
 
 
+pub use error_sink::ErrorSink;
 pub use into_result::IntoResult;
+pub use iter_ext::{BindIterExt, OkValues, TakeUntilErr};
+pub use try_unwrap::TryUnwrap;
 
 
 /// Binds the unwrapped value
@@ -31,23 +37,63 @@ pub use into_result::IntoResult;
 /// # Syntax
 ///
 /// ```text
-/// bind!([mut] <var-name> [= <value-expr>], or [<err-handler>,] <flow-ctl>);
+/// bind!([<attrs>] [mut] <var-name> [= <value-expr>], or [<err-handler>,] <flow-ctl> [, assert <condition>]);
+/// bind!([<attrs>] [mut] <var-name> [= <value-expr>]);
+/// bind!([<attrs>] [mut] <var-name> = keep <value-expr>, or [<err-handler>,] <flow-ctl>);
+/// bind!([<attrs>] [mut] <var-name> [= <value-expr>], as <err-type>, or [<err-handler>,] <flow-ctl> [, assert <condition>]);
+/// bind!([<attrs>] [mut] <var-name> [= <value-expr>], or_else <recovery>, or [<err-handler>,] <flow-ctl> [, assert <condition>]);
 /// ```
 ///
-/// - `mut` — indicator keyword to make the binding mutable.
-/// - `<var-name>` — name of the newly created variable.
+/// - `<attrs>` — optional attributes, such as `#[allow(unused_variables)]`,
+///   applied to the generated `let` statement.
+/// - `mut` — indicator keyword to make the binding mutable. The unwrapped value is moved
+///   into the binding directly, as with the handwritten `let mut` and `match`.
+/// - `<var-name>` — name of the newly created variable, or `_` to only test the value,
+///   such as a `bool` condition. Then the unwrapped value isn't accessible, not even
+///   in `<condition>`, but it's still dropped at the end of the scope rather than immediately.
 /// - `<value-expr>` — expression whose value is [being tested](IntoResult) to contain
 ///   an unwrappable value. If not specified, the existing value of the variable `<var-name>`
 ///   will be used to create new variable with the same name.
+/// - `<err-type>` — optional type of the [`IntoResult::Error`] of the value, for the cases
+///   when it can't be inferred, such as when the handler is a closure without annotations.
+/// - `<recovery>` — function or closure called with the error of `<value-expr>`
+///   if there's no value to unwrap, returning the replacement that is
+///   [tested](IntoResult) instead. Then `<err-handler>` gets the error of the replacement.
 /// - `<err-handler>` — optional error handler that is called if there's no value to unwrap,
 ///   with error object passed as the only argument.
+/// - `<err-handler>` may be `capture <sink>`, where `<sink>` is a place expression
+///   of a type implementing [`ErrorSink`], such as `Option<E>` or `&mut Vec<E>`.
+///   Then the error is passed to the sink, so it can be inspected after `<flow-ctl>`,
+///   such as after the loop. Not supported with the `throw` flow control expression.
 /// - `<flow-ctl>` — expression used to control the execution flow in a case
 ///   when there's no value to unwrap.
+/// - `<flow-ctl>` may be `none`, short for `return None`, or `err(<error>)`, short for
+///   `return Err(<error>.into())`.
+/// - `<flow-ctl>` may also be `throw`, which returns the error converted with [`Into`],
+///   like the `?` operator does. Then `<err-handler>` is called with the reference
+///   to the error instead.
+/// - The `or` clause may be omitted if the [`IntoResult::Error`] of the value
+///   is [`Infallible`](std::convert::Infallible), so there's no error to handle.
+/// - `keep` — indicator keyword to [test](TryUnwrap) `<value-expr>` with [`TryUnwrap`]
+///   instead of [`IntoResult`], passing the original value to `<err-handler>`.
+/// - `<condition>` — optional condition that the bound value must satisfy, checked
+///   with [`debug_assert!`]. The panic message includes the condition and the
+///   [`Debug`](std::fmt::Debug) representation of the value.
+///
+/// With the `stats` feature, each call site counts the times there was a value to bind
+/// and the times there wasn't, inspected with `stats::snapshot()`.
+///
+/// Inside coroutines, `<flow-ctl>` may be `yield <value>`. Then the value is yielded
+/// and, once the coroutine is resumed, `<value-expr>` is evaluated and tested again,
+/// until there's a value to unwrap. The temporaries of `<value-expr>` are dropped
+/// before yielding, so they aren't held while the coroutine is suspended. Covered by
+/// the tests with the `nightly-coroutine` feature, which requires a nightly compiler.
 ///
 /// # Examples
 ///
 /// Basic usage:
-/// ```
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
 /// # use el_macro::bind;
 /// #
 /// // binds `x` to the value 42, does not return
@@ -68,7 +114,8 @@ pub use into_result::IntoResult;
 /// ```
 ///
 /// Handling error values:
-/// ```
+#[cfg_attr(all(feature = "impl-option", feature = "impl-result"), doc = "```")]
+#[cfg_attr(not(all(feature = "impl-option", feature = "impl-result")), doc = "```ignore")]
 /// # use el_macro::bind;
 /// #
 /// let okish = Some(42).ok_or("error");
@@ -96,8 +143,121 @@ pub use into_result::IntoResult;
 /// unreachable!();
 /// ```
 ///
-/// Omitting the `<value-expr>`:
+/// Returning from the functions that return [`Option`] or [`Result`]:
+#[cfg_attr(all(feature = "impl-option", feature = "impl-result"), doc = "```")]
+#[cfg_attr(not(all(feature = "impl-option", feature = "impl-result")), doc = "```ignore")]
+/// # use el_macro::bind;
+/// #
+/// fn first_even(values: &[i32]) -> Option<i32> {
+///     bind!(value = values.iter().find(|value| *value % 2 == 0), or none);
+///     Some(*value)
+/// }
+///
+/// fn parse_port(s: &str) -> Result<u16, String> {
+///     bind!(port = s.parse::<u16>(), or |err| eprintln!("{err}"), err("invalid port"));
+///     Ok(port)
+/// }
+///
+/// fn parse_sum(a: &str, b: &str) -> Result<i64, Box<dyn std::error::Error>> {
+///     bind!(a = a.parse::<i64>(), or throw);
+///     bind!(b = b.parse::<i64>(), or |err| eprintln!("{err}"), throw);
+///     Ok(a + b)
+/// }
+///
+/// assert_eq!(first_even(&[1, 42]), Some(42));
+/// assert_eq!(first_even(&[1]), None);
+/// assert_eq!(parse_port("8080"), Ok(8080));
+/// assert_eq!(parse_port("http"), Err("invalid port".to_string()));
+/// assert_eq!(parse_sum("40", "2").unwrap(), 42);
+/// assert!(parse_sum("40", "two").is_err());
 /// ```
+///
+/// Asserting the bound value:
+#[cfg_attr(feature = "impl-option", doc = "```should_panic")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::bind;
+/// #
+/// const MAX: usize = 4;
+///
+/// bind!(len = Some("abc").map(str::len), or return, assert len <= MAX);
+/// assert_eq!(len, 3);
+///
+/// // panics with '`len <= MAX` doesn't hold for `len` = 5' in debug builds
+/// bind!(len = Some("hello").map(str::len), or return, assert len <= MAX);
+/// # #[cfg(not(debug_assertions))] panic!();
+/// ```
+///
+/// Testing the conditions:
+/// ```
+/// # use el_macro::bind;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// enum Error { Forbidden }
+///
+/// fn delete(user: &str) -> Result<(), Error> {
+///     let is_admin = |user: &str| user == "root";
+///     bind!(_ = is_admin(user), or err(Error::Forbidden));
+///     Ok(())
+/// }
+///
+/// assert_eq!(delete("root"), Ok(()));
+/// assert_eq!(delete("guest"), Err(Error::Forbidden));
+/// ```
+///
+/// Specifying the error type:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::bind;
+/// #
+/// // the error type of `Ok(42)` can't be inferred otherwise
+/// bind!(x = Ok(42), as String, or |err| eprintln!("{err}"), return);
+/// assert_eq!(x, 42);
+/// ```
+///
+/// Recovering from the error with a replacement value:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::bind;
+/// # use std::{env, env::VarError};
+/// #
+/// let var = |name: &str| env::var(name);
+///
+/// // falls back to `EL_MACRO_USER` if `EL_MACRO_LOGIN` isn't set,
+/// // but not if it isn't valid unicode
+/// bind!(user = var("EL_MACRO_LOGIN"), or_else |err| match err {
+///     VarError::NotPresent => var("EL_MACRO_USER"),
+///     err => Err(err),
+/// }, or {
+///     // neither is set, returns
+///     return
+/// });
+/// unreachable!();
+/// ```
+///
+/// Binding the values that can't fail:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::bind;
+/// # use std::convert::Infallible;
+/// #
+/// fn parse_or_zero(s: &str) -> Result<i32, Infallible> {
+///     Ok(s.parse().unwrap_or(0))
+/// }
+///
+/// bind!(x = parse_or_zero("42"));
+/// assert_eq!(x, 42);
+/// ```
+///
+/// The `or` clause can only be omitted if the error is `Infallible`:
+/// ```compile_fail
+/// # use el_macro::bind;
+/// #
+/// bind!(x = "42".parse::<i32>());
+/// ```
+///
+/// Omitting the `<value-expr>`:
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
 /// # use el_macro::bind;
 /// #
 /// let x = Some(42);
@@ -161,36 +321,177 @@ pub use into_result::IntoResult;
 #[macro_export]
 macro_rules! bind {
 
-    ($n: ident = $e: expr, or $h: expr, $f: expr) => {
-        let $n = {
-            use $crate::bind::IntoResult;
-            match $e.into_result() {
+    (@value $a: tt $m: tt $n: ident = keep $e: expr, or $($rest: tt)+) => {
+        $crate::bind!(@or ($a $m $n TryUnwrap [$e.try_unwrap()]) $($rest)+);
+    };
+
+    (@value $a: tt $m: tt $n: ident = $e: expr, as $t: ty, or $($rest: tt)+) => {
+        $crate::bind!(@or ($a $m $n IntoResult [
+            ::core::convert::identity::<::core::result::Result<_, $t>>($e.into_result())
+        ]) $($rest)+);
+    };
+
+    (@value $a: tt $m: tt $n: ident, as $t: ty, or $($rest: tt)+) => {
+        $crate::bind!(@or ($a $m $n IntoResult [
+            ::core::convert::identity::<::core::result::Result<_, $t>>($n.into_result())
+        ]) $($rest)+);
+    };
+
+    (@value $a: tt $m: tt $n: ident = $e: expr, or_else $r: expr, or $($rest: tt)+) => {
+        $crate::bind!(@or ($a $m $n IntoResult [$crate::bind!(@recover $e, $r)]) $($rest)+);
+    };
+
+    (@value $a: tt $m: tt $n: ident, or_else $r: expr, or $($rest: tt)+) => {
+        $crate::bind!(@or ($a $m $n IntoResult [$crate::bind!(@recover $n, $r)]) $($rest)+);
+    };
+
+    (@value $a: tt $m: tt $n: ident = $e: expr, or $($rest: tt)+) => {
+        $crate::bind!(@or ($a $m $n IntoResult [$e.into_result()]) $($rest)+);
+    };
+
+    (@value $a: tt $m: tt $n: ident, or $($rest: tt)+) => {
+        $crate::bind!(@or ($a $m $n IntoResult [$n.into_result()]) $($rest)+);
+    };
+
+    (@value $a: tt $m: tt $n: ident = $e: expr) => {
+        $crate::bind!(@never ($a $m $n IntoResult [$e.into_result()]));
+    };
+
+    (@value $a: tt $m: tt $n: ident) => {
+        $crate::bind!(@never ($a $m $n IntoResult [$n.into_result()]));
+    };
+
+    (@or $b: tt capture $s: expr, $($rest: tt)+) => {
+        $crate::bind!(@or $b (|err| { use $crate::bind::ErrorSink; $s.capture(err) }), $($rest)+);
+    };
+
+    (@or $b: tt yield $y: expr $(, assert $c: expr)?) => {
+        $crate::bind!(@yield $b [|_| { }] [$y] [$($c)?]);
+    };
+
+    (@or $b: tt $h: expr, yield $y: expr $(, assert $c: expr)?) => {
+        $crate::bind!(@yield $b [$h] [$y] [$($c)?]);
+    };
+
+    (@or $b: tt throw $(, assert $c: expr)?) => {
+        $crate::bind!(@throw $b [] [$($c)?]);
+    };
+
+    (@or $b: tt $h: expr, throw $(, assert $c: expr)?) => {
+        $crate::bind!(@throw $b [$h] [$($c)?]);
+    };
+
+    (@or $b: tt none $(, assert $c: expr)?) => {
+        $crate::bind!(@bind $b [|_| { }] [return ::core::option::Option::None] [$($c)?]);
+    };
+
+    (@or $b: tt $h: expr, none $(, assert $c: expr)?) => {
+        $crate::bind!(@bind $b [$h] [return ::core::option::Option::None] [$($c)?]);
+    };
+
+    (@or $b: tt err($x: expr) $(, assert $c: expr)?) => {
+        $crate::bind!(@bind $b [|_| { }]
+            [return ::core::result::Result::Err(::core::convert::Into::into($x))] [$($c)?]);
+    };
+
+    (@or $b: tt $h: expr, err($x: expr) $(, assert $c: expr)?) => {
+        $crate::bind!(@bind $b [$h]
+            [return ::core::result::Result::Err(::core::convert::Into::into($x))] [$($c)?]);
+    };
+
+    (@or $b: tt $f: expr $(, assert $c: expr)?) => {
+        $crate::bind!(@bind $b [|_| { }] [$f] [$($c)?]);
+    };
+
+    (@or $b: tt $h: expr, $f: expr $(, assert $c: expr)?) => {
+        $crate::bind!(@bind $b [$h] [$f] [$($c)?]);
+    };
+
+    (@bind ([$($a: tt)*] [$($m: tt)?] $n: ident $t: ident [$($s: tt)+]) [$h: expr] [$f: expr] [$($c: expr)?]) => {
+        $($a)* let $($m)? $n = {
+            use $crate::bind::$t;
+            match $crate::__bind_stats!($n, $($s)+) {
                 Ok($n) => { $n },
                 Err(err) => {
-                    $h(err);
+                    $crate::private::call_with($h, err);
                     $f
                 },
             }
         };
+        $($crate::bind!(@assert $n, $c);)?
     };
 
-    ($n: ident, or $h: expr, $f: expr) => {
-        $crate::bind!($n = $n, or $h, $f);
+    (@throw ([$($a: tt)*] [$($m: tt)?] $n: ident $t: ident [$($s: tt)+]) [$($h: expr)?] [$($c: expr)?]) => {
+        $($a)* let $($m)? $n = {
+            use $crate::bind::$t;
+            match $crate::__bind_stats!($n, $($s)+) {
+                Ok($n) => { $n },
+                Err(err) => {
+                    $(
+                        $crate::private::call_with($h, &err);
+                    )?
+                    return ::core::result::Result::Err(::core::convert::Into::into(err))
+                },
+            }
+        };
+        $($crate::bind!(@assert $n, $c);)?
     };
 
-    ($n: ident $(= $e: expr)?, or $f: expr) => {
-        $crate::bind!($n $(= $e)?, or |_| { }, $f);
+    (@yield ([$($a: tt)*] [$($m: tt)?] $n: ident $t: ident [$($s: tt)+]) [$h: expr] [$y: expr] [$($c: expr)?]) => {
+        $($a)* let $($m)? $n = loop {
+            let result = {
+                use $crate::bind::$t;
+                $crate::__bind_stats!($n, $($s)+)
+            };
+            match result {
+                Ok($n) => break $n,
+                Err(err) => {
+                    $crate::private::call_with($h, err);
+                    yield $y;
+                },
+            }
+        };
+        $($crate::bind!(@assert $n, $c);)?
     };
-    
-    (mut $n: ident $(= $e: expr)?, or $h: expr, $f: expr) => {
-        let mut $n = {
-            $crate::bind!($n $(= $e)?, or $h, $f);
-            $n
+
+    (@never ([$($a: tt)*] [$($m: tt)?] $n: ident $t: ident [$($s: tt)+])) => {
+        $($a)* let $($m)? $n = {
+            use $crate::bind::$t;
+            match $crate::__bind_stats!($n, $($s)+) {
+                Ok($n) => { $n },
+                Err(err) => {
+                    let never: ::core::convert::Infallible = err;
+                    match never { }
+                },
+            }
         };
     };
-    
-    (mut $n: ident $(= $e: expr)?, or $f: expr) => {
-        $crate::bind!(mut $n $(= $e)?, or |_| { }, $f);
+
+    (@recover $e: expr, $r: expr) => {
+        match $e.into_result() {
+            ::core::result::Result::Ok(value) => ::core::result::Result::Ok(value),
+            ::core::result::Result::Err(err) => $crate::private::call_with($r, err).into_result(),
+        }
+    };
+
+    (@assert $n: ident, $c: expr) => {
+        ::core::debug_assert!(
+            $c,
+            "`{}` doesn't hold for `{}` = {:?}",
+            ::core::stringify!($c), ::core::stringify!($n), $n,
+        );
+    };
+
+    ($(#[$a: meta])* _ $($rest: tt)*) => {
+        $crate::bind!(@value [$(#[$a])*] [] _value $($rest)*);
+    };
+
+    ($(#[$a: meta])* mut $n: ident $($rest: tt)*) => {
+        $crate::bind!(@value [$(#[$a])*] [mut] $n $($rest)*);
+    };
+
+    ($(#[$a: meta])* $n: ident $($rest: tt)*) => {
+        $crate::bind!(@value [$(#[$a])*] [] $n $($rest)*);
     };
 
 }
@@ -22,6 +22,50 @@ fn mut_with_handler() {
 }
 
 
+#[test]
+fn attributes_apply_to_let() {
+
+    let mut y = 0;
+    bind!(#[allow(unused_variables, unused_mut)] mut x = None::<i32>, or |_| y += 1, {
+        assert_eq!(y, 1);
+        return
+    });
+    unreachable!()
+
+}
+
+
+#[test]
+fn mut_non_copy_with_handler() {
+
+    #[derive(Debug)]
+    struct Log(Vec<&'static str>);
+
+    let entries: Result<_, ()> = Ok(Log(vec!["start"]));
+    bind!(mut log = entries, or |_| unreachable!(), return, assert !log.0.is_empty());
+    log.0.push("stop");
+    assert_eq!(log.0, ["start", "stop"]);
+
+}
+
+
+#[test]
+#[cfg(debug_assertions)]
+fn mut_binds_in_place() {
+
+    // the `assert` clause is checked on the declared variable itself,
+    // so the address stays the same unless the value is moved again
+    let mut checked_at = 0;
+    bind!(mut buffer = Some([0_u8; 64]), or return, assert {
+        checked_at = buffer.as_ptr() as usize;
+        true
+    });
+    buffer[0] = 42;
+    assert_eq!(buffer.as_ptr() as usize, checked_at);
+
+}
+
+
 #[test]
 fn deref_and_shorthand() {
 
@@ -29,11 +73,215 @@ fn deref_and_shorthand() {
     bind!(x, or return);
     assert_eq!(x, 42);
 
-    use std::sync::{Arc, Mutex};
+    #[cfg(feature = "impl-mutex")]
+    {
+        use std::sync::{Arc, Mutex};
+
+        let x = Arc::new(Mutex::new(45));
+        bind!(mut x, or return);
+        *x -= 3;
+        assert_eq!(*x, 42);
+    }
+
+}
+
+
+#[test]
+fn keep_gives_back_original() {
+
+    use crate::bind::TryUnwrap;
+
+    struct Even(u32);
+
+    impl TryUnwrap for Even {
+
+        type Value = u32;
+
+        fn try_unwrap(self) -> Result<Self::Value, Self> {
+            if self.0 % 2 == 0 { Ok(self.0) } else { Err(self) }
+        }
+
+    }
+
+    let mut odd = Vec::new();
+    for n in 1..=4 {
+        bind!(mut n = keep Even(n), or |Even(n)| odd.push(n), continue);
+        n /= 2;
+        assert!(n == 1 || n == 2);
+    }
+    assert_eq!(odd, [1, 3]);
+
+}
+
+
+#[test]
+fn clauses_compose() {
+
+    use std::{error::Error, num::ParseIntError};
+
+    fn parse(s: &str) -> Option<u32> {
+        bind!(mut n = s.parse(), as ParseIntError, or |err| eprintln!("{err}"), none, assert n < 100);
+        n *= 2;
+        Some(n)
+    }
+
+    fn halve(s: &str) -> Result<u32, Box<dyn Error>> {
+        bind!(n = s.parse::<u32>(), or throw, assert n % 2 == 0);
+        Ok(n / 2)
+    }
+
+    assert_eq!(parse("21"), Some(42));
+    assert_eq!(parse("x"), None);
+    assert_eq!(halve("84").ok(), Some(42));
+    assert!(halve("x").is_err());
+
+}
+
+
+#[test]
+fn take_until_err_keeps_error() {
+
+    use crate::bind::BindIterExt;
+
+    let mut iter = [Ok(4), Ok(2), Err("x"), Ok(0)].into_iter().take_until_err();
+    assert_eq!(iter.by_ref().collect::<Vec<_>>(), [4, 2]);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.error(), Some(&"x"));
+
+}
+
+
+#[test]
+fn break_out_of_scope() {
+
+    let sum = |values: &[Option<i32>]| crate::bind_scope! {
+        let mut sum = 0;
+        for value in values {
+            bind!(value, or continue);
+            sum += value;
+        }
+        bind!(first = values[0], or break);
+        sum + first
+    };
+
+    assert_eq!(sum(&[Some(1), None, Some(40)]), Some(42));
+    assert_eq!(sum(&[None, Some(42)]), None);
+
+}
+
+
+#[cfg(feature = "nightly-coroutine")]
+#[test]
+fn yield_until_ready() {
+
+    use std::{
+        cell::{Cell, RefCell},
+        ops::{Coroutine, CoroutineState},
+        pin::pin,
+    };
+
+    let input = RefCell::new(Vec::<&str>::new());
+    let polls = Cell::new(0);
+    let mut parser = pin!(#[coroutine] || {
+        bind!(mut line = input.borrow_mut().pop(), or |_| polls.set(polls.get() + 1), yield "more");
+        line = line.trim();
+        bind!(n = line.parse::<i32>(), or yield "invalid");
+        n
+    });
+
+    assert_eq!(parser.as_mut().resume(()), CoroutineState::Yielded("more"));
+    assert_eq!(parser.as_mut().resume(()), CoroutineState::Yielded("more"));
+    input.borrow_mut().push(" 42 ");
+    assert_eq!(parser.as_mut().resume(()), CoroutineState::Complete(42));
+    assert_eq!(polls.get(), 2);
+
+}
+
+
+#[cfg(feature = "nightly-coroutine")]
+#[test]
+fn yield_until_ready_then_assert() {
+
+    use std::{
+        cell::Cell,
+        ops::{Coroutine, CoroutineState},
+        pin::pin,
+    };
+
+    let input = Cell::new(None);
+    let mut reader = pin!(#[coroutine] || {
+        bind!(n = input.take(), or yield "more", assert n > 0);
+        n
+    });
+
+    assert_eq!(reader.as_mut().resume(()), CoroutineState::Yielded("more"));
+    assert_eq!(reader.as_mut().resume(()), CoroutineState::Yielded("more"));
+    input.set(Some(42));
+    assert_eq!(reader.as_mut().resume(()), CoroutineState::Complete(42));
+
+}
+
+
+#[cfg(all(feature = "nightly-coroutine", debug_assertions))]
+#[test]
+#[should_panic(expected = "`n > 0` doesn't hold for `n` = 0")]
+fn yield_checks_assert() {
+
+    use std::{ops::Coroutine, pin::pin};
+
+    let mut input = vec![0];
+    let mut reader = pin!(#[coroutine] || {
+        bind!(n = input.pop(), or |_| { }, yield (), assert n > 0);
+        n
+    });
+
+    let _ = reader.as_mut().resume(());
+
+}
+
+
+#[test]
+fn or_else_recovers_from_error() {
+
+    fn lookup(id: u32) -> Result<&'static str, u32> {
+        match id {
+            1 => Ok("alice"),
+            _ => Err(id),
+        }
+    }
+
+    bind!(name = lookup(2), or_else |id| lookup(id - 1), or return);
+    assert_eq!(name, "alice");
+
+    let mut errors = Vec::new();
+    for id in [3, 1] {
+        let name = lookup(id);
+        bind!(name, or_else lookup, or |err| errors.push(err), continue);
+        assert_eq!(name, "alice");
+    }
+    assert_eq!(errors, [3]);
+
+}
+
+
+#[test]
+fn capture_into_sink() {
+
+    fn parse_all(input: &[&str], errors: &mut Vec<std::num::ParseIntError>) -> Vec<i32> {
+        let mut values = Vec::new();
+        for s in input {
+            bind!(n = s.parse(), or capture errors, continue);
+            values.push(n);
+        }
+        values
+    }
+
+    let mut errors = Vec::new();
+    assert_eq!(parse_all(&["4", "x", "2", ""], &mut errors), [4, 2]);
+    assert_eq!(errors.len(), 2);
 
-    let x = Arc::new(Mutex::new(45));
-    bind!(mut x, or return);
-    *x -= 3;
-    assert_eq!(*x, 42);
+    let mut last = None;
+    bind!(_ = false, or capture last, { });
+    assert_eq!(last, Some(crate::error::False));
 
 }
@@ -0,0 +1,49 @@
+/// Tells the [`crate::bind!`] macro whether the given expression has a bindable value,
+/// giving the expression value back otherwise.
+///
+/// Used by the [`crate::bind!`] macro instead of [`IntoResult`](super::IntoResult)
+/// if the value expression is preceded by the `keep` keyword. Then the original value
+/// is passed to the optional error handler, so the handler may keep it, such as
+/// to return it to a pool or to retry with it later. Meant for wrappers that must not
+/// be destroyed when there's no value to bind.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, bind::TryUnwrap};
+/// #
+/// struct Slot(Option<String>);
+///
+/// impl TryUnwrap for Slot {
+///
+///     type Value = String;
+///
+///     fn try_unwrap(self) -> Result<Self::Value, Self> {
+///         match self.0 {
+///             Some(value) => Ok(value),
+///             None => Err(self),
+///         }
+///     }
+///
+/// }
+///
+/// let mut free = Vec::new();
+/// let mut taken = Vec::new();
+///
+/// for slot in [Slot(Some("a".into())), Slot(None), Slot(Some("b".into()))] {
+///     bind!(value = keep slot, or |slot| free.push(slot), continue);
+///     taken.push(value);
+/// }
+///
+/// assert_eq!(taken, ["a", "b"]);
+/// assert_eq!(free.len(), 1);
+/// ```
+pub trait TryUnwrap: Sized {
+
+    /// Type of the value that the [`crate::bind!`] macro binds the created variable to.
+    type Value;
+
+    /// Unwraps the value or gives the original value back.
+    fn try_unwrap(self) -> Result<Self::Value, Self>;
+
+}
@@ -0,0 +1,67 @@
+//! The [`crate::bind_all!`] macro
+
+
+/// Binds the unwrapped values of all the items of a collection
+///
+/// Works like the [`crate::bind!`] macro, but the value expression is a collection,
+/// or any other [`IntoIterator`], of [unwrappable](crate::bind::IntoResult) items.
+/// Binds the variable to the [`Vec`] of the unwrapped values if all the items can be unwrapped.
+/// Otherwise, passes the error of the first failed item, along with its index,
+/// as [`IndexedError`](crate::error::IndexedError) to the optional error handler
+/// and evaluates the execution flow control expression.
+///
+/// # Syntax
+///
+/// ```text
+/// bind_all!([mut] <var-name> = <items-expr>, or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `mut`, `<var-name>`, `<err-handler>`, `<flow-ctl>` — same as for
+///   the [`crate::bind!`] macro.
+/// - `<items-expr>` — [`IntoIterator`] of items implementing [`IntoResult`](crate::bind::IntoResult).
+///
+/// # Examples
+///
+/// Basic usage:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::bind_all;
+/// #
+/// let inputs = ["1", "2", "3"];
+///
+/// bind_all!(numbers = inputs.iter().map(|s| s.parse::<i32>()), or return);
+/// assert_eq!(numbers, [1, 2, 3]);
+/// ```
+///
+/// Handling error values:
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::{bind_all, error::IndexedError};
+/// #
+/// let report = |err: IndexedError<()>| eprintln!("field {} is missing", err.index);
+/// let fields = vec![Some("id"), None, Some("name")];
+///
+/// // prints 'field 1 is missing' and returns
+/// bind_all!(fields = fields, or report, return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! bind_all {
+
+    ($n: ident = $e: expr, or $h: expr, $f: expr) => {
+        $crate::bind!($n = $crate::adapters::AllOk($e), or $h, $f)
+    };
+
+    ($n: ident = $e: expr, or $f: expr) => {
+        $crate::bind!($n = $crate::adapters::AllOk($e), or $f)
+    };
+
+    (mut $n: ident = $e: expr, or $h: expr, $f: expr) => {
+        $crate::bind!(mut $n = $crate::adapters::AllOk($e), or $h, $f)
+    };
+
+    (mut $n: ident = $e: expr, or $f: expr) => {
+        $crate::bind!(mut $n = $crate::adapters::AllOk($e), or $f)
+    };
+
+}
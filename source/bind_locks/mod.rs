@@ -0,0 +1,142 @@
+//! The [`crate::bind_locks!`] macro
+
+
+#[cfg(all(test, feature = "impl-result"))]
+mod test;
+
+
+/// Acquires several locks at once and binds their guards
+///
+/// Acquires the provided [`Mutex`](std::sync::Mutex) and [`RwLock`](std::sync::RwLock)
+/// locks in the order of their addresses rather than in the order they are listed in,
+/// so that the invocations acquiring the same locks can't deadlock each other.
+/// Binds the guards to the patterns in the order the locks are listed in if all the locks
+/// are acquired. Otherwise, if a lock is poisoned, releases the acquired ones, executes
+/// the error handler with the [`PoisonedLock`](crate::error::PoisonedLock) error holding
+/// the position of the poisoned lock and evaluates the execution flow control expression.
+///
+/// # Syntax
+///
+/// ```text
+/// bind_locks!((<pattern>, ...) = (<lock>, ...), or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `<pattern>` — irrefutable pattern the guard of the respective lock is bound to,
+///   such as `mut a` to modify the protected data.
+/// - `<lock>` — expression of a reference to the [`Mutex`](std::sync::Mutex) to lock,
+///   or `read <expression>` or `write <expression>` of a reference to
+///   the [`RwLock`](std::sync::RwLock) to acquire for reading or writing respectively.
+///   Up to 8 locks are supported.
+/// - `<err-handler>`, `<flow-ctl>` — same as for the [`crate::bind!`] macro.
+///   `<err-handler>` receives the [`PoisonedLock`](crate::error::PoisonedLock).
+///
+/// # Panics
+///
+/// Panics if the same lock is listed more than once, which would otherwise deadlock.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::bind_locks;
+/// # use std::sync::{Mutex, RwLock};
+/// #
+/// let from = Mutex::new(100);
+/// let to = Mutex::new(0);
+/// let log = RwLock::new(Vec::new());
+///
+/// let transfer = |from: &Mutex<i32>, to: &Mutex<i32>, amount: i32| {
+///     // acquires the accounts in the same order regardless of the direction
+///     bind_locks!((mut from, mut to, mut log) = (from, to, write &log), or return);
+///     *from -= amount;
+///     *to += amount;
+///     log.push(amount);
+/// };
+///
+/// transfer(&from, &to, 42);
+/// transfer(&to, &from, 2);
+/// assert_eq!(*from.lock().unwrap(), 60);
+/// assert_eq!(*to.lock().unwrap(), 40);
+/// assert_eq!(*log.read().unwrap(), [42, 2]);
+/// ```
+///
+/// Handling poisoned locks:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::bind_locks;
+/// # use std::sync::{Mutex, RwLock};
+/// #
+/// let config = RwLock::new("el-macro");
+/// let state = Mutex::new(0);
+///
+/// let _ = std::panic::catch_unwind(|| {
+///     let _guard = state.lock();
+///     panic!("poisons the state");
+/// });
+///
+/// // prints 'lock 1 is poisoned' and returns
+/// bind_locks!((config, state) = (read &config, &state), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! bind_locks {
+
+    (@locks [$($acc: tt)*] [$l: ident $g: ident $($ids: tt)*]
+        read $e: expr $(, $($rest: tt)*)?) => {
+        $crate::bind_locks!(@locks [$($acc)* ($l $g [$crate::private::ReadLock($e)])]
+            [$($ids)*] $($($rest)*)?)
+    };
+
+    (@locks [$($acc: tt)*] [$l: ident $g: ident $($ids: tt)*]
+        write $e: expr $(, $($rest: tt)*)?) => {
+        $crate::bind_locks!(@locks [$($acc)* ($l $g [$crate::private::WriteLock($e)])]
+            [$($ids)*] $($($rest)*)?)
+    };
+
+    (@locks [$($acc: tt)*] [$l: ident $g: ident $($ids: tt)*]
+        $e: expr $(, $($rest: tt)*)?) => {
+        $crate::bind_locks!(@locks [$($acc)* ($l $g [$e])] [$($ids)*] $($($rest)*)?)
+    };
+
+    (@locks [$(($l: ident $g: ident [$e: expr]))+] [$($ids: tt)*]) => {
+        {
+            $(let $l = $e;)+
+            // the items are declared in a nested block, so that they don't shadow
+            // the names used in the lock expressions
+            {
+                use $crate::private::OrderedLock;
+                #[allow(non_camel_case_types)]
+                #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+                enum Index { $($l),+ }
+                $(let mut $g = None;)+
+                let mut order = [$(($l.address(), Index::$l)),+];
+                order.sort_unstable();
+                if order.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+                    ::core::panic!("the same lock is passed to `bind_locks!` more than once");
+                }
+                let mut poisoned = None;
+                for (_, index) in order {
+                    let acquired = match index {
+                        $(Index::$l => $l.acquire().map(|guard| $g = Some(guard)),)+
+                    };
+                    if acquired.is_none() {
+                        poisoned = Some(index as usize);
+                        break;
+                    }
+                }
+                match poisoned {
+                    Some(index) => ::core::result::Result::Err($crate::error::PoisonedLock { index }),
+                    None => ::core::result::Result::Ok(($($g.expect("the lock is acquired"),)+)),
+                }
+            }
+        }
+    };
+
+    (($($p: pat_param),+ $(,)?) = ($($l: tt)+), or $($rest: tt)+) => {
+        $crate::bind!(guards = $crate::bind_locks!(@locks []
+            [l0 g0 l1 g1 l2 g2 l3 g3 l4 g4 l5 g5 l6 g6 l7 g7] $($l)+),
+            or $($rest)+);
+        let ($($p,)+) = guards;
+    };
+
+}
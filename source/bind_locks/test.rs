@@ -0,0 +1,99 @@
+use std::sync::{Mutex, RwLock};
+
+use crate::{bind_locks, error::PoisonedLock};
+
+
+fn poison<T>(lock: &Mutex<T>) {
+
+    let _ = std::panic::catch_unwind(|| {
+        let _guard = lock.lock();
+        panic!("poisons the lock");
+    });
+
+}
+
+
+#[test]
+fn guards_follow_listed_order() {
+
+    let locks = [Mutex::new(0), Mutex::new(1), Mutex::new(2)];
+
+    // lists the locks against the order of their addresses
+    bind_locks!((c, b, a) = (&locks[2], &locks[1], &locks[0]), or unreachable!());
+    assert_eq!((*a, *b, *c), (0, 1, 2));
+
+}
+
+
+#[test]
+fn opposite_orders_do_not_deadlock() {
+
+    let first = Mutex::new(0);
+    let second = Mutex::new(0);
+
+    std::thread::scope(|scope| {
+        for forward in [true, false] {
+            let (first, second) = (&first, &second);
+            scope.spawn(move || {
+                for _ in 0..1000 {
+                    let (from, to) = if forward { (first, second) } else { (second, first) };
+                    bind_locks!((mut from, mut to) = (from, to), or unreachable!());
+                    *from -= 1;
+                    *to += 1;
+                }
+            });
+        }
+    });
+
+    assert_eq!(*first.lock().unwrap(), 0);
+    assert_eq!(*second.lock().unwrap(), 0);
+
+}
+
+
+#[test]
+fn read_and_write_locks() {
+
+    let config = RwLock::new("el-macro");
+    let log = RwLock::new(Vec::new());
+
+    {
+        bind_locks!((name, mut entries) = (read &config, write &log), or unreachable!());
+        entries.push(*name);
+    }
+
+    // a read lock may be held by several readers
+    let _reader = config.read().unwrap();
+    bind_locks!((name, entries) = (read &config, read &log), or unreachable!());
+    assert_eq!(*entries, [*name]);
+
+}
+
+
+#[test]
+fn poisoned_lock_index() {
+
+    let locks = [Mutex::new(0), Mutex::new(1), Mutex::new(2)];
+    poison(&locks[1]);
+
+    let mut error = None;
+    bind_locks!((_a, _b, _c) = (&locks[2], &locks[0], &locks[1]), or |err| error = Some(err), {
+        assert_eq!(error, Some(PoisonedLock { index: 2 }));
+        // the acquired locks are released
+        assert!(locks[0].try_lock().is_ok());
+        assert!(locks[2].try_lock().is_ok());
+        return
+    });
+    unreachable!()
+
+}
+
+
+#[test]
+#[should_panic(expected = "the same lock is passed to `bind_locks!` more than once")]
+fn same_lock_twice_panics() {
+
+    let lock = Mutex::new(0);
+    bind_locks!((_a, _b) = (&lock, &lock), or return);
+
+}
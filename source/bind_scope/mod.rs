@@ -0,0 +1,50 @@
+//! The [`crate::bind_scope!`] macro
+
+
+/// Evaluates the statements in a scope that `break` and `continue` exit
+///
+/// Evaluates the provided statements and yields [`Some`] with the value of the last expression,
+/// like a block does. Yields [`None`] if the scope is exited early with unlabeled `break`
+/// or `continue`, such as by the `or break` clause of the [`crate::bind!`] macro, without the need
+/// to come up with a label for the block.
+///
+/// Loops within the statements still capture their own `break` and `continue`.
+///
+/// # Syntax
+///
+/// ```text
+/// bind_scope! { <statements> }
+/// ```
+///
+/// - `<statements>` — statements evaluated within the scope, like in a block.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::{bind, bind_scope};
+/// #
+/// let area = |width: &str, height: &str| bind_scope! {
+///     bind!(w = width.parse::<u32>(), or break);
+///     bind!(h = height.parse::<u32>(), or break);
+///     w * h
+/// };
+///
+/// assert_eq!(area("6", "7"), Some(42));
+/// assert_eq!(area("6", "x"), None);
+/// ```
+#[macro_export]
+macro_rules! bind_scope {
+
+    ($($s: tt)*) => {
+        {
+            let mut value = ::core::option::Option::None;
+            #[allow(clippy::never_loop)]
+            for _ in 0..1 {
+                value = ::core::option::Option::Some({ $($s)* });
+            }
+            value
+        }
+    };
+
+}
@@ -0,0 +1,82 @@
+//! The [`crate::bind_static!`] macro
+
+
+/// Binds the reference to the lazily initialized static value
+///
+/// Declares a hidden static [`OnceLock`](std::sync::OnceLock) at the call site and binds
+/// the variable to the reference to its value. On the first use, initializes the static
+/// with the unwrapped value of the provided expression. If the value can't be unwrapped,
+/// executes the error handler and evaluates the execution flow control expression,
+/// leaving the static uninitialized, so the initialization is attempted again on the next use.
+///
+/// If several threads initialize the static concurrently, each of them evaluates the expression,
+/// and all of them bind the value of the one that completes first. The others' values are dropped.
+///
+/// # Syntax
+///
+/// ```text
+/// bind_static!(<var-name>: <type> = <value-expr>, or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `<var-name>` — name of the newly created variable holding `&'static <type>`.
+/// - `<type>` — type of the static value. It can't depend on the generic parameters
+///   of the enclosing function.
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value, evaluated only while the static is uninitialized.
+/// - `<err-handler>`, `<flow-ctl>` — same as for the [`crate::bind!`] macro.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::bind_static;
+/// #
+/// struct Client { url: String }
+///
+/// fn build_client(url: &str) -> Option<Client> {
+///     (!url.is_empty()).then(|| Client { url: url.into() })
+/// }
+///
+/// fn client(url: &str) -> Option<&'static Client> {
+///     bind_static!(CLIENT: Client = build_client(url), or return None);
+///     Some(CLIENT)
+/// }
+///
+/// // fails, the static stays uninitialized
+/// assert!(client("").is_none());
+/// // initializes the static
+/// assert_eq!(client("localhost").unwrap().url, "localhost");
+/// // the static is already initialized, `build_client` is not called
+/// assert_eq!(client("").unwrap().url, "localhost");
+/// ```
+#[macro_export]
+macro_rules! bind_static {
+
+    ($n: ident: $t: ty = $e: expr, or $h: expr, $f: expr) => {
+        #[allow(non_snake_case)]
+        let $n: &'static $t = {
+            let cell: &'static ::std::sync::OnceLock<$t> = {
+                static CELL: ::std::sync::OnceLock<$t> = ::std::sync::OnceLock::new();
+                &CELL
+            };
+            match cell.get() {
+                Some(value) => value,
+                None => {
+                    use $crate::bind::IntoResult;
+                    match $e.into_result() {
+                        Ok(value) => cell.get_or_init(|| value),
+                        Err(err) => {
+                            $crate::private::call_with($h, err);
+                            $f
+                        },
+                    }
+                },
+            }
+        };
+    };
+
+    ($n: ident: $t: ty = $e: expr, or $f: expr) => {
+        $crate::bind_static!($n: $t = $e, or |_| { }, $f);
+    };
+
+}
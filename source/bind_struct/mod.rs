@@ -0,0 +1,118 @@
+//! The [`crate::bind_struct!`] macro
+
+
+/// Constructs a struct from the unwrapped values of the fallible field expressions
+///
+/// Evaluates the provided field expressions in order and [tests](crate::bind::IntoResult)
+/// whether the value of each can be unwrapped. Yields the struct with the fields initialized
+/// with the unwrapped values if all of them can be unwrapped. Otherwise, executes the error
+/// handler with the [`FieldError`](crate::error::FieldError) naming the first field that
+/// can't be unwrapped, and evaluates the execution flow control expression, without
+/// evaluating the rest of the field expressions.
+///
+/// The field values may be of different types, while the errors must be of the same type.
+/// The [`FieldError`](crate::error::FieldError) converts into
+/// [`MissingFields`](crate::error::MissingFields), for reporting it the same way as
+/// the [`crate::require_fields!`] macro does.
+///
+/// # Syntax
+///
+/// ```text
+/// bind_struct!(<struct-path> { <field> [: <value-expr>] [, <field> [: <value-expr>]]* }, or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `<struct-path>` — path of the struct to construct.
+/// - `<field>` — name of the field. If `<value-expr>` is not specified, the value
+///   of the variable with the same name is used, as in the field init shorthand.
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+/// - `<err-handler>` — optional error handler that is called if there's no value to unwrap,
+///   with the [`FieldError`](crate::error::FieldError) passed as the only argument.
+/// - `<flow-ctl>` — expression used to control the execution flow in a case
+///   when there's no value to unwrap.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::{bind_struct, error::FieldError};
+/// # use std::collections::HashMap;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// struct Config {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// let vars = HashMap::from([("HOST", "localhost"), ("PORT", "8080")]);
+/// let env = |name: &str| vars.get(name).map(|value| value.to_string()).ok_or("not set");
+///
+/// let port = env("PORT").map(|port| port.parse().unwrap_or(80));
+/// let config = bind_struct!(Config { host: env("HOST"), port }, or return);
+/// assert_eq!(config, Config { host: "localhost".into(), port: 8080 });
+///
+/// // prints 'field `port`: not set' and returns
+/// let config = bind_struct!(Config { host: env("HOST"), port: env("SOCKET").map(|_| 0) },
+///     or |err: FieldError<&str>| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+///
+/// Reporting the missing field:
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::{bind_struct, error::{FieldError, MissingFields}};
+/// #
+/// struct Endpoint {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// fn endpoint(host: Option<String>, port: Option<u16>) -> Result<Endpoint, MissingFields> {
+///     let mut missing = MissingFields::new();
+///     let endpoint = bind_struct!(Endpoint { host, port },
+///         or |err: FieldError<_>| missing = err.into(), return Err(missing));
+///     Ok(endpoint)
+/// }
+///
+/// let err = endpoint(Some("localhost".into()), None).err().unwrap();
+/// assert_eq!(err.to_string(), "missing: port");
+/// ```
+#[macro_export]
+macro_rules! bind_struct {
+
+    (@value $f: ident) => {
+        $f
+    };
+
+    (@value $f: ident $e: expr) => {
+        $e
+    };
+
+    ($($s: ident)::+ { $($f: ident $(: $e: expr)?),+ $(,)? }, or $h: expr, $c: expr) => {
+        {
+            use $crate::bind::IntoResult;
+            let result = 'fields: {
+                ::core::result::Result::Ok($($s)::+ {$(
+                    $f: match $crate::bind_struct!(@value $f $($e)?).into_result() {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(error) => break 'fields ::core::result::Result::Err(
+                            $crate::error::FieldError { field: ::core::stringify!($f), error },
+                        ),
+                    },
+                )+})
+            };
+            match result {
+                ::core::result::Result::Ok(value) => value,
+                ::core::result::Result::Err(err) => {
+                    $crate::private::call_with($h, err);
+                    $c
+                },
+            }
+        }
+    };
+
+    ($($s: ident)::+ { $($f: ident $(: $e: expr)?),+ $(,)? }, or $c: expr) => {
+        $crate::bind_struct!($($s)::+ { $($f $(: $e)?),+ }, or |_| { }, $c)
+    };
+
+}
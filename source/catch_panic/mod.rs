@@ -0,0 +1,49 @@
+//! The [`crate::catch_panic!`] macro and related [`Panic`] error type
+
+
+pub use crate::error::Panic;
+
+
+/// Evaluates the expression, catching the panic if it occurs
+///
+/// Evaluates the provided expression inside [`std::panic::catch_unwind`] and yields
+/// [`Result`] holding either the value of the expression or the [`Panic`] error,
+/// so panicking calls can be bound with the [`crate::bind!`] macro as any other fallible source.
+///
+/// The expression is [asserted](std::panic::AssertUnwindSafe) to be unwind safe. It is evaluated
+/// inside a closure, so `return`, `break`, `continue` and `?` within it do not affect
+/// the enclosing function or loop. Panics are only caught with the `panic = "unwind"` strategy.
+///
+/// # Syntax
+///
+/// ```text
+/// catch_panic!(<expression>)
+/// ```
+///
+/// - `<expression>` — expression that might panic.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::{bind, catch_panic};
+/// #
+/// let x = catch_panic!(42);
+/// assert!(x.is_ok_and(|x| x == 42));
+///
+/// let x = catch_panic!(i32::from_str_radix("42", 1));
+/// assert!(x.is_err_and(|err| err.message().is_some_and(|msg| msg.contains("radix"))));
+///
+/// // prints 'panicked: boom' and returns
+/// bind!(x = catch_panic!(panic!("boom")), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! catch_panic {
+
+    ($e: expr) => {
+        ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| $e))
+            .map_err($crate::error::Panic::from)
+    };
+
+}
@@ -0,0 +1,72 @@
+//! The [`crate::cfg_bind!`] macro
+
+
+/// Binds the unwrapped value of the expression selected by configuration predicates
+///
+/// Selects the value expression of the first arm whose configuration predicate holds,
+/// as `#[cfg(...)]` would evaluate it, and binds it the same way the [`crate::bind!`] macro does.
+/// The expressions of the other arms are not compiled. The optional `_` arm is selected
+/// if none of the predicates hold.
+///
+/// Fails to compile with a "cannot find value" error if none of the arms is selected.
+///
+/// # Syntax
+///
+/// ```text
+/// cfg_bind!([mut] <var-name> = { [<predicate>: <value-expr>],+ [, _: <value-expr>] },
+///     or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `<predicate>` — configuration predicate, as accepted by the `cfg` attribute.
+/// - `mut`, `<var-name>`, `<value-expr>`, `<err-handler>`, `<flow-ctl>` — same as for
+///   the [`crate::bind!`] macro.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::cfg_bind;
+/// #
+/// fn unix_home() -> Option<&'static str> { Some("/home") }
+/// fn windows_home() -> Option<&'static str> { Some("C:\\Users") }
+///
+/// cfg_bind!(home = {
+///     unix: unix_home(),
+///     windows: windows_home(),
+///     _: None::<&str>,
+/// }, or return);
+///
+/// #[cfg(unix)]
+/// assert_eq!(home, "/home");
+///
+/// cfg_bind!(mut x = { any(): Some(0), _: Some(41) }, or return);
+/// x += 1;
+/// assert_eq!(x, 42);
+/// ```
+#[macro_export]
+macro_rules! cfg_bind {
+
+    (@select $v: ident [$($c: meta),*]) => { };
+
+    (@select $v: ident [$($c: meta),*] _: $e: expr $(,)?) => {
+        #[cfg(not(any($($c),*)))]
+        let $v = $e;
+    };
+
+    (@select $v: ident [$($c: meta),*] $p: meta: $e: expr $(, $($rest: tt)*)?) => {
+        #[cfg(all($p, not(any($($c),*))))]
+        let $v = $e;
+        $crate::cfg_bind!(@select $v [$($c,)* $p] $($($rest)*)?);
+    };
+
+    ($n: ident = { $($s: tt)* }, or $($clause: tt)*) => {
+        $crate::cfg_bind!(@select value [] $($s)*);
+        $crate::bind!($n = value, or $($clause)*);
+    };
+
+    (mut $n: ident = { $($s: tt)* }, or $($clause: tt)*) => {
+        $crate::cfg_bind!(@select value [] $($s)*);
+        $crate::bind!(mut $n = value, or $($clause)*);
+    };
+
+}
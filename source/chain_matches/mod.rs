@@ -0,0 +1,114 @@
+//! The [`crate::chain_matches!`] macro
+
+
+/// Maps the bound variables of a chain of patterns to [`Some`] if all the steps of the chain hold
+///
+/// Evaluates the steps of the chain in order. A step is either a binding, which holds
+/// if the provided expression matches the pattern, or a boolean guard. The variables bound
+/// by a step are available in the later steps and the mapping expression.
+/// Evaluates the mapping expression into [`Some`] if all the steps hold.
+/// Returns [`None`] as soon as a step doesn't hold, without evaluating the later steps.
+///
+/// Generalizes [`crate::if_matches!`] from a single pattern to a pipeline of them.
+///
+/// # Syntax
+///
+/// ```text
+/// chain_matches! {
+///     [<step>;]+ => <mapping>
+/// }
+/// ```
+///
+/// - `<step>` — one of:
+///   - `<pattern> = <expression>` — holds if `<expression>` matches `<pattern>`;
+///   - `<guard>` — boolean expression, holds if it evaluates to `true`.
+/// - `<mapping>` — expression the bound variables are mapped into [`Some`] value with.
+///
+/// A step is treated as a binding if it contains `=` outside of any brackets.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use el_macro::chain_matches;
+/// #
+/// struct User { active: bool, name: &'static str }
+///
+/// let find_user = |id: u32| (id < 3).then_some(User { active: id != 0, name: "ann" });
+/// let load_session = |user: User| if user.name.is_empty() {
+///     Err("no name")
+/// } else {
+///     Ok(format!("token-{}", user.name))
+/// };
+///
+/// let token = |id| chain_matches! {
+///     Some(u) = find_user(id);
+///     u.active;
+///     Ok(s) = load_session(u);
+///     => s
+/// };
+///
+/// assert_eq!(token(1).as_deref(), Some("token-ann"));
+/// assert_eq!(token(0), None);
+/// assert_eq!(token(7), None);
+/// ```
+#[macro_export]
+macro_rules! chain_matches {
+
+    (@chain [] $m: expr) => {
+        ::core::option::Option::Some($m)
+    };
+
+    (@chain [(let [$($p: tt)*] [$($e: tt)*]) $($rest: tt)*] $m: expr) => {
+        if let $($p)* = $($e)* {
+            $crate::chain_matches!(@chain [$($rest)*] $m)
+        } else {
+            ::core::option::Option::None
+        }
+    };
+
+    (@chain [(if [$($g: tt)*]) $($rest: tt)*] $m: expr) => {
+        if $($g)* {
+            $crate::chain_matches!(@chain [$($rest)*] $m)
+        } else {
+            ::core::option::Option::None
+        }
+    };
+
+    (@step [$($s: tt)*] [] => $m: expr $(;)?) => {
+        $crate::chain_matches!(@chain [$($s)*] $m)
+    };
+
+    (@step [$($s: tt)*] [$($g: tt)+] => $m: expr $(;)?) => {
+        $crate::chain_matches!(@chain [$($s)* (if [$($g)+])] $m)
+    };
+
+    (@step [$($s: tt)*] [$($g: tt)+] ; $($rest: tt)+) => {
+        $crate::chain_matches!(@step [$($s)* (if [$($g)+])] [] $($rest)+)
+    };
+
+    (@step [$($s: tt)*] [$($p: tt)+] = $($rest: tt)+) => {
+        $crate::chain_matches!(@bind [$($s)*] [$($p)+] [] $($rest)+)
+    };
+
+    (@step [$($s: tt)*] [$($c: tt)*] $t: tt $($rest: tt)+) => {
+        $crate::chain_matches!(@step [$($s)*] [$($c)* $t] $($rest)+)
+    };
+
+    (@bind [$($s: tt)*] [$($p: tt)+] [$($e: tt)+] => $m: expr $(;)?) => {
+        $crate::chain_matches!(@chain [$($s)* (let [$($p)+] [$($e)+])] $m)
+    };
+
+    (@bind [$($s: tt)*] [$($p: tt)+] [$($e: tt)+] ; $($rest: tt)+) => {
+        $crate::chain_matches!(@step [$($s)* (let [$($p)+] [$($e)+])] [] $($rest)+)
+    };
+
+    (@bind [$($s: tt)*] [$($p: tt)+] [$($e: tt)*] $t: tt $($rest: tt)+) => {
+        $crate::chain_matches!(@bind [$($s)*] [$($p)+] [$($e)* $t] $($rest)+)
+    };
+
+    ($($t: tt)+) => {
+        $crate::chain_matches!(@step [] [] $($t)+)
+    };
+
+}
@@ -0,0 +1,79 @@
+//! The [`crate::collect_matches!`] macro
+
+
+/// Collects the mapped bound variables of the items of an iterator that match the pattern
+///
+/// Matches each item of the provided [`IntoIterator`] against the pattern and collects
+/// the pattern's bound variables of the items that match the pattern and the optional
+/// match guard, mapped by the provided expression. Skips the items that don't match.
+/// Yields the collection, which is a [`Vec`] unless another [`FromIterator`] type is specified.
+///
+/// # Syntax
+///
+/// ```text
+/// collect_matches!(<items-expr>, <pattern> [if <match-guard>] => <mapping> [, into <collection-type>])
+/// ```
+///
+/// - `<items-expr>` — [`IntoIterator`] of items to be matched against `<pattern>`.
+/// - `<pattern>` — pattern that is matched against.
+/// - `<match-guard>` — optional match guard that further restricts the match.
+/// - `<mapping>` — expression the bound variables of a matching item are mapped into
+///   the item of the collection with.
+/// - `<collection-type>` — optional [`FromIterator`] type of the collection,
+///   [`Vec`] if not specified.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use el_macro::collect_matches;
+/// #
+/// struct Metric { name: &'static str, value: f64 }
+///
+/// enum Line { Comment, Metric(Metric) }
+///
+/// let lines = [
+///     Line::Metric(Metric { name: "cpu", value: 0.5 }),
+///     Line::Comment,
+///     Line::Metric(Metric { name: "mem", value: 0.25 }),
+/// ];
+///
+/// let metrics = collect_matches!(lines, Line::Metric(m) => (m.name, m.value));
+/// assert_eq!(metrics, [("cpu", 0.5), ("mem", 0.25)]);
+/// ```
+///
+/// Collecting into another type:
+/// ```
+/// # use el_macro::collect_matches;
+/// # use std::collections::HashMap;
+/// #
+/// let pairs = ["a=1", "junk", "b=2", "c=x"];
+///
+/// let config = collect_matches!(
+///     pairs.iter().map(|pair| pair.split_once('=')),
+///     Some((key, value)) if !value.is_empty() => (key, value.parse::<i32>()),
+///     into HashMap<_, _>
+/// );
+/// assert_eq!(config["a"], Ok(1));
+/// assert!(config["c"].is_err());
+/// ```
+#[macro_export]
+macro_rules! collect_matches {
+
+    ($i: expr, $p: pat $(if $c: expr)? => $m: expr, into $t: ty) => {
+        ::core::iter::Iterator::collect::<$t>(
+            ::core::iter::Iterator::filter_map(
+                ::core::iter::IntoIterator::into_iter($i),
+                |item| match item {
+                    $p $(if $c)? => ::core::option::Option::Some($m),
+                    _ => ::core::option::Option::None,
+                },
+            ),
+        )
+    };
+
+    ($i: expr, $p: pat $(if $c: expr)? => $m: expr) => {
+        $crate::collect_matches!($i, $p $(if $c)? => $m, into ::std::vec::Vec<_>)
+    };
+
+}
@@ -0,0 +1,63 @@
+//! The [`crate::collect_ok!`] macro
+
+
+/// Collects the unwrapped values into [`Vec`], stopping at the first failure
+///
+/// Evaluates the provided fallible expression for every item of the iterable
+/// and collects the unwrapped values. Stops at the first value that
+/// [can't be unwrapped](crate::bind::IntoResult). Yields [`Ok`] with the collected values,
+/// or [`Err`] with the [`IndexedError`](crate::error::IndexedError) holding the error
+/// and the position of the item it occurred at.
+///
+/// The expression is evaluated inside a loop, so `break` and `continue` within it
+/// affect that loop, while `return` and `?` exit the enclosing function.
+///
+/// # Syntax
+///
+/// ```text
+/// collect_ok!(for <pattern> in <iterable> => <fallible-expr>)
+/// ```
+///
+/// - `<pattern>` — pattern the items are bound to within `<fallible-expr>`.
+/// - `<iterable>` — expression of a type implementing [`IntoIterator`].
+/// - `<fallible-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::{bind, collect_ok, error::IndexedError};
+/// #
+/// let x = collect_ok!(for s in ["4", "2"] => s.parse::<i32>());
+/// assert_eq!(x, Ok(vec![4, 2]));
+///
+/// let x = collect_ok!(for s in ["4", "2", "x", "y"] => s.parse::<i32>());
+/// let IndexedError { index, error } = x.unwrap_err();
+/// assert_eq!(index, 2);
+/// assert_eq!(error.to_string(), "invalid digit found in string");
+///
+/// // prints 'item 1: invalid digit found in string' and returns
+/// bind!(x = collect_ok!(for s in ["4", "x"] => s.parse::<i32>()),
+///     or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! collect_ok {
+
+    (for $p: pat in $i: expr => $e: expr) => {
+        'collect: {
+            use $crate::bind::IntoResult;
+            let iter = ::std::iter::IntoIterator::into_iter($i);
+            let mut values = ::std::vec::Vec::with_capacity(iter.size_hint().0);
+            for (index, $p) in ::std::iter::Iterator::enumerate(iter) {
+                match $e.into_result() {
+                    Ok(value) => values.push(value),
+                    Err(error) => break 'collect Err($crate::error::IndexedError { index, error }),
+                }
+            }
+            Ok(values)
+        }
+    };
+
+}
@@ -0,0 +1,137 @@
+//! The [`crate::compose!`] macro and related [`StepError`] error type
+
+
+pub use crate::error::StepError;
+
+
+/// Threads the value through a sequence of fallible steps and yields or binds the final value
+///
+/// Evaluates the provided expression and [tests](crate::bind::IntoResult) whether its value
+/// can be unwrapped. Passes the unwrapped value to the first function, tests its result
+/// the same way, passes the unwrapped result to the second function, and so on,
+/// stopping at the first step that fails. Reports the failed step with the [`StepError`].
+///
+/// In the expression form, yields [`Ok`] with the unwrapped result of the last step
+/// or [`Err`] with the [`StepError`]. In the binding form, binds the unwrapped result
+/// of the last step the same way the [`crate::bind!`] macro does.
+///
+/// # Syntax
+///
+/// ```text
+/// compose!(<expression> [=> <fn>]* [, as <err-type>])
+/// compose!([mut] <var-name> = <expression> [=> <fn>]+ [, as <err-type>], or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `<expression>` — expression whose value is the first step of the pipeline.
+/// - `<fn>` — function, closure or any other callable expression taking the unwrapped value
+///   of the previous step and returning the value of the next one.
+/// - `<err-type>` — optional type the errors of the steps are converted into with [`Into`].
+///   If not specified, the errors of all the steps must be of the same type.
+/// - `mut`, `<var-name>`, `<err-handler>`, `<flow-ctl>` — same as for
+///   the [`crate::bind!`] macro. `<err-handler>` receives the [`StepError`].
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::compose;
+/// # use std::error::Error;
+/// #
+/// fn read(path: &str) -> Result<&'static str, &'static str> {
+///     match path {
+///         "port.txt" => Ok("8080"),
+///         "old-port.txt" => Ok("80"),
+///         "host.txt" => Ok("localhost"),
+///         _ => Err("no such file"),
+///     }
+/// }
+///
+/// fn parse(text: &str) -> Result<u16, Box<dyn Error>> {
+///     Ok(text.parse()?)
+/// }
+///
+/// fn validate(port: u16) -> Result<u16, &'static str> {
+///     if port >= 1024 { Ok(port) } else { Err("privileged port") }
+/// }
+///
+/// compose!(port = read("port.txt") => parse => validate, as Box<dyn Error>, or return);
+/// assert_eq!(port, 8080);
+///
+/// let port = compose!(read("old-port.txt") => parse => validate, as Box<dyn Error>);
+/// assert!(port.is_err_and(|err| err.step == "validate"));
+///
+/// // prints 'step `parse`: invalid digit found in string' and returns
+/// compose!(port = read("host.txt") => parse => validate, as Box<dyn Error>,
+///     or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+///
+/// The steps with the errors of the same type don't need the `as` clause:
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::compose;
+/// #
+/// let digit = compose!("42".chars().next() => |c: char| c.to_digit(10));
+/// assert_eq!(digit, Ok(4));
+/// ```
+#[macro_export]
+macro_rules! compose {
+
+    (@convert [] $err: ident) => {
+        $err
+    };
+
+    (@convert [$t: ty] $err: ident) => {
+        ::core::convert::Into::<$t>::into($err)
+    };
+
+    (@step $l: lifetime $t: tt $s: expr, $r: expr) => {
+        match {
+            use $crate::bind::IntoResult;
+            $r.into_result()
+        } {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(err) => break $l ::core::result::Result::Err(
+                $crate::error::StepError {
+                    step: ::core::stringify!($s),
+                    error: $crate::compose!(@convert $t err),
+                },
+            ),
+        }
+    };
+
+    (@steps $t: tt $e: expr $(=> $f: expr)*) => {
+        'compose: {
+            let value = $crate::compose!(@step 'compose $t $e, $e);
+            $(
+                let value = $crate::compose!(@step 'compose $t $f, $crate::private::call_with($f, value));
+            )*
+            ::core::result::Result::Ok(value)
+        }
+    };
+
+    (mut $n: ident = $e: expr $(=> $f: expr)+, as $t: ty, or $($rest: tt)+) => {
+        $crate::bind!(mut $n = $crate::compose!(@steps [$t] $e $(=> $f)+), or $($rest)+);
+    };
+
+    (mut $n: ident = $e: expr $(=> $f: expr)+, or $($rest: tt)+) => {
+        $crate::bind!(mut $n = $crate::compose!(@steps [] $e $(=> $f)+), or $($rest)+);
+    };
+
+    ($n: ident = $e: expr $(=> $f: expr)+, as $t: ty, or $($rest: tt)+) => {
+        $crate::bind!($n = $crate::compose!(@steps [$t] $e $(=> $f)+), or $($rest)+);
+    };
+
+    ($n: ident = $e: expr $(=> $f: expr)+, or $($rest: tt)+) => {
+        $crate::bind!($n = $crate::compose!(@steps [] $e $(=> $f)+), or $($rest)+);
+    };
+
+    ($e: expr $(=> $f: expr)*, as $t: ty) => {
+        $crate::compose!(@steps [$t] $e $(=> $f)*)
+    };
+
+    ($e: expr $(=> $f: expr)*) => {
+        $crate::compose!(@steps [] $e $(=> $f)*)
+    };
+
+}
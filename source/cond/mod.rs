@@ -0,0 +1,134 @@
+//! The [`crate::cond!`] macro
+
+
+/// Yields the value of the first arm whose conditions hold
+///
+/// Evaluates the conditions of the arms in order and yields the value of the first arm
+/// all of whose conditions hold, or the value of the `else` arm if none does.
+/// Condenses `if`/`else if` ladders into a single expression.
+///
+/// An arm may have several conditions separated by `;`. Besides boolean guards,
+/// a condition may be a `let` binding of a refutable pattern or a `bind` binding
+/// of an [unwrappable value](crate::bind::IntoResult). The variables bound by a condition
+/// are available in the later conditions and the value of the same arm.
+///
+/// # Syntax
+///
+/// ```text
+/// cond! {
+///     [<condition> [; <condition>]* => <value>,]*
+///     else => <value>
+/// }
+/// ```
+///
+/// - `<condition>` — one of:
+///   - `<guard>` — boolean expression;
+///   - `let <pattern> = <expression>` — holds if `<expression>` matches `<pattern>`;
+///   - `bind <var-name> = <value-expr>` — holds if the value of `<value-expr>`
+///     [can be unwrapped](crate::bind::IntoResult), binding `<var-name>` to it.
+/// - `<value>` — expression the macro yields if all the conditions of the arm hold.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use el_macro::cond;
+/// #
+/// let describe = |n: i32| cond! {
+///     n < 0 => "negative",
+///     n == 0 => "zero",
+///     n < 10 => "small",
+///     else => "large",
+/// };
+///
+/// assert_eq!(describe(-1), "negative");
+/// assert_eq!(describe(5), "small");
+/// assert_eq!(describe(42), "large");
+/// ```
+///
+/// Using bindings:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::cond;
+/// #
+/// let classify = |s: &str| cond! {
+///     s.is_empty() => "empty".to_string(),
+///     bind n = s.parse::<i32>(); n % 2 == 0 => format!("even {n}"),
+///     let Some(c) = s.chars().next(); c.is_alphabetic() => format!("word on {c}"),
+///     else => "something else".to_string(),
+/// };
+///
+/// assert_eq!(classify("42"), "even 42");
+/// assert_eq!(classify("43"), "something else");
+/// assert_eq!(classify("hi"), "word on h");
+/// ```
+#[macro_export]
+macro_rules! cond {
+
+    (@if $l: lifetime ([] $v: expr)) => {
+        break $l $v;
+    };
+
+    (@if $l: lifetime ([(let $p: pat = $e: expr) $($rest: tt)*] $v: expr)) => {
+        if let $p = $e {
+            $crate::cond!(@if $l ([$($rest)*] $v))
+        }
+    };
+
+    (@if $l: lifetime ([(bind $n: ident = $e: expr) $($rest: tt)*] $v: expr)) => {
+        if let Ok($n) = {
+            use $crate::bind::IntoResult;
+            $e.into_result()
+        } {
+            $crate::cond!(@if $l ([$($rest)*] $v))
+        }
+    };
+
+    (@if $l: lifetime ([($g: expr) $($rest: tt)*] $v: expr)) => {
+        if $g {
+            $crate::cond!(@if $l ([$($rest)*] $v))
+        }
+    };
+
+    (@arms $l: lifetime [$($arm: tt)*] else => $v: expr $(,)?) => {
+        $l: {
+            $($crate::cond!(@if $l $arm);)*
+            $v
+        }
+    };
+
+    (@arms $l: lifetime [$($arm: tt)*] $($rest: tt)+) => {
+        $crate::cond!(@clause $l [$($arm)*] [] $($rest)+)
+    };
+
+    (@clause $l: lifetime [$($arm: tt)*] [$($c: tt)*] let $p: pat = $e: expr; $($rest: tt)+) => {
+        $crate::cond!(@clause $l [$($arm)*] [$($c)* (let $p = $e)] $($rest)+)
+    };
+
+    (@clause $l: lifetime [$($arm: tt)*] [$($c: tt)*]
+        let $p: pat = $e: expr => $v: expr, $($rest: tt)+) => {
+        $crate::cond!(@arms $l [$($arm)* ([$($c)* (let $p = $e)] $v)] $($rest)+)
+    };
+
+    (@clause $l: lifetime [$($arm: tt)*] [$($c: tt)*] bind $n: ident = $e: expr; $($rest: tt)+) => {
+        $crate::cond!(@clause $l [$($arm)*] [$($c)* (bind $n = $e)] $($rest)+)
+    };
+
+    (@clause $l: lifetime [$($arm: tt)*] [$($c: tt)*]
+        bind $n: ident = $e: expr => $v: expr, $($rest: tt)+) => {
+        $crate::cond!(@arms $l [$($arm)* ([$($c)* (bind $n = $e)] $v)] $($rest)+)
+    };
+
+    (@clause $l: lifetime [$($arm: tt)*] [$($c: tt)*] $g: expr; $($rest: tt)+) => {
+        $crate::cond!(@clause $l [$($arm)*] [$($c)* ($g)] $($rest)+)
+    };
+
+    (@clause $l: lifetime [$($arm: tt)*] [$($c: tt)*] $g: expr => $v: expr, $($rest: tt)+) => {
+        $crate::cond!(@arms $l [$($arm)* ([$($c)* ($g)] $v)] $($rest)+)
+    };
+
+    ($($t: tt)+) => {
+        $crate::cond!(@arms 'cond [] $($t)+)
+    };
+
+}
@@ -0,0 +1,54 @@
+//! The [`crate::count_matches!`] macro
+
+
+/// Counts the items of an iterator that match the pattern
+///
+/// Matches each item of the provided [`IntoIterator`] against the pattern and counts
+/// the items that match the pattern and the optional match guard, which may refer
+/// to the variables bound by the pattern.
+///
+/// # Syntax
+///
+/// ```text
+/// count_matches!(<items-expr>, <pattern> [if <match-guard>])
+/// ```
+///
+/// - `<items-expr>` — [`IntoIterator`] of items to be matched against `<pattern>`.
+/// - `<pattern>` — pattern that is matched against.
+/// - `<match-guard>` — optional match guard that further restricts the match.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::count_matches;
+/// #
+/// enum Packet { Data { len: usize }, Retransmit { seq: u32 }, Ack }
+///
+/// let packets = [
+///     Packet::Data { len: 1500 },
+///     Packet::Retransmit { seq: 7 },
+///     Packet::Ack,
+///     Packet::Data { len: 40 },
+///     Packet::Retransmit { seq: 9 },
+/// ];
+///
+/// assert_eq!(count_matches!(&packets, Packet::Retransmit { .. }), 2);
+/// assert_eq!(count_matches!(&packets, Packet::Data { len } if *len > 100), 1);
+/// ```
+#[macro_export]
+macro_rules! count_matches {
+
+    ($i: expr, $p: pat $(if $c: expr)?) => {
+        {
+            let mut count = 0_usize;
+            for item in $i {
+                match item {
+                    $p $(if $c)? => count += 1,
+                    _ => { },
+                }
+            }
+            count
+        }
+    };
+
+}
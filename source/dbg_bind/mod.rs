@@ -0,0 +1,108 @@
+//! The [`crate::dbg_bind!`] macro
+
+
+/// Works like the [`crate::bind!`] macro, but also prints the outcome of the value expression
+///
+/// In debug builds, prints the file and line of the invocation, the value expression
+/// and the [`Debug`](std::fmt::Debug) representation of its value
+/// [represented as `Result`](crate::bind::IntoResult) to the standard error,
+/// like the [`dbg!`] macro does, before binding the value or handling the error.
+/// In release builds, is the same as the [`crate::bind!`] macro.
+///
+/// Allows temporarily debugging a binding without restructuring the statement.
+///
+/// # Syntax
+///
+/// Same as for the [`crate::bind!`] macro. Both the value and the error must implement
+/// [`Debug`](std::fmt::Debug). With the `keep` keyword, the outcome of [`TryUnwrap`]
+/// is printed instead, so the original value must implement [`Debug`](std::fmt::Debug).
+///
+/// [`TryUnwrap`]: crate::bind::TryUnwrap
+///
+/// # Examples
+///
+#[cfg_attr(all(feature = "impl-option", feature = "impl-result"), doc = "```")]
+#[cfg_attr(not(all(feature = "impl-option", feature = "impl-result")), doc = "```ignore")]
+/// # use el_macro::dbg_bind;
+/// #
+/// // prints in debug builds:
+/// // [src/main.rs:7] "42".parse::<i32>() = Ok(
+/// //     42,
+/// // )
+/// dbg_bind!(x = "42".parse::<i32>(), or return);
+/// assert_eq!(x, 42);
+///
+/// // prints in debug builds and returns:
+/// // [src/main.rs:15] y = Err(
+/// //     (),
+/// // )
+/// let y = None::<i32>;
+/// dbg_bind!(y, or return);
+/// unreachable!();
+/// ```
+///
+/// Combining the clauses of the [`crate::bind!`] macro:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::dbg_bind;
+/// #
+/// // prints the outcome of `"http".parse::<u16>()`, but not of the recovery
+/// dbg_bind!(port = "http".parse::<u16>(), or_else |_| "80".parse(), or return, assert port > 0);
+/// assert_eq!(port, 80);
+///
+/// dbg_bind!(_ = port < 1024, or return);
+/// ```
+#[macro_export]
+macro_rules! dbg_bind {
+
+    (@dbg $e: expr) => {
+        {
+            use $crate::bind::IntoResult;
+            let result = $e.into_result();
+            #[cfg(debug_assertions)]
+            ::std::eprintln!(
+                "[{}:{}] {} = {:#?}",
+                ::core::file!(), ::core::line!(), ::core::stringify!($e), &result,
+            );
+            result
+        }
+    };
+
+    (@dbg_keep $e: expr) => {
+        {
+            use $crate::bind::TryUnwrap;
+            let result = $e.try_unwrap();
+            #[cfg(debug_assertions)]
+            ::std::eprintln!(
+                "[{}:{}] {} = {:#?}",
+                ::core::file!(), ::core::line!(), ::core::stringify!($e), &result,
+            );
+            result
+        }
+    };
+
+    (@value $a: tt $m: tt $n: ident = keep $e: expr, or $($rest: tt)+) => {
+        $crate::bind!(@or ($a $m $n TryUnwrap [$crate::dbg_bind!(@dbg_keep $e)]) $($rest)+);
+    };
+
+    (@value $a: tt $m: tt $n: ident = $e: expr $(, $($rest: tt)+)?) => {
+        $crate::bind!(@value $a $m $n = $crate::dbg_bind!(@dbg $e) $(, $($rest)+)?);
+    };
+
+    (@value $a: tt $m: tt $n: ident $(, $($rest: tt)+)?) => {
+        $crate::bind!(@value $a $m $n = $crate::dbg_bind!(@dbg $n) $(, $($rest)+)?);
+    };
+
+    ($(#[$a: meta])* _ $($rest: tt)*) => {
+        $crate::dbg_bind!(@value [$(#[$a])*] [] _value $($rest)*);
+    };
+
+    ($(#[$a: meta])* mut $n: ident $($rest: tt)*) => {
+        $crate::dbg_bind!(@value [$(#[$a])*] [mut] $n $($rest)*);
+    };
+
+    ($(#[$a: meta])* $n: ident $($rest: tt)*) => {
+        $crate::dbg_bind!(@value [$(#[$a])*] [] $n $($rest)*);
+    };
+
+}
@@ -0,0 +1,97 @@
+//! The [`crate::deser_field!`] macro and related [`DeserError`] error type
+
+
+use std::{any::type_name, borrow::Borrow};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+
+pub use crate::error::DeserError;
+
+
+/// Navigates the dotted path in the value and deserializes the field.
+///
+/// Used by the [`crate::deser_field!`] macro. The segments of the path are the keys
+/// of the objects or the indices of the arrays.
+pub fn extract<'a, T, S>(source: &'a S, path: &'static str) -> Result<T, DeserError>
+where
+    T: Deserialize<'a>,
+    S: Borrow<Value> + ?Sized,
+{
+    let missing = || DeserError { path, type_name: type_name::<T>(), error: None };
+    let mut value = source.borrow();
+    for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+        value = match value {
+            Value::Array(items) => segment.parse().ok().and_then(|index: usize| items.get(index)),
+            value => value.get(segment),
+        }.ok_or_else(missing)?;
+    }
+    T::deserialize(value)
+        .map_err(|error| DeserError { path, type_name: type_name::<T>(), error: Some(error) })
+}
+
+
+/// Deserializes the field at the dotted path of the JSON value or controls the execution flow
+///
+/// Navigates the dotted path in the [`serde_json::Value`], where the segments are the keys
+/// of the objects or the indices of the arrays, and deserializes the field to the type.
+/// Yields the deserialized value. Otherwise, executes the error handler with
+/// the [`DeserError`] naming the path and the type, and evaluates the execution flow
+/// control expression. Without the `or` clause, yields [`Result`] instead.
+///
+/// Available with the `json` feature.
+///
+/// # Syntax
+///
+/// ```text
+/// deser_field!(<source>, <path> as <type>)
+/// deser_field!(<source>, <path> as <type>, or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `<source>` — place expression of the [`serde_json::Value`] or the reference to it.
+/// - `<path>` — string literal of the dotted path of the field, such as `"server.port"`.
+///   The empty path refers to `<source>` itself.
+/// - `<type>` — type implementing [`Deserialize`] the field is deserialized to.
+/// - `<err-handler>`, `<flow-ctl>` — same as for the [`crate::bind!`] macro.
+///   `<err-handler>` receives the [`DeserError`].
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::deser_field;
+/// # use serde_json::json;
+/// #
+/// let cfg = json!({
+///     "server": { "host": "localhost", "port": 8080 },
+///     "users": [{ "name": "alice" }],
+/// });
+///
+/// let port = deser_field!(cfg, "server.port" as u16, or return);
+/// assert_eq!(port, 8080);
+///
+/// let name = deser_field!(cfg, "users.0.name" as &str, or return);
+/// assert_eq!(name, "alice");
+///
+/// let tls = deser_field!(cfg, "server.tls" as bool);
+/// assert!(tls.is_err_and(|err| err.error.is_none()));
+///
+/// // prints '`server.host` as `u16`: invalid type: string "localhost", expected u16' and returns
+/// let port = deser_field!(cfg, "server.host" as u16, or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! deser_field {
+
+    ($s: expr, $p: literal as $t: ty, or $($rest: tt)+) => {
+        {
+            $crate::bind!(value = $crate::deser_field!($s, $p as $t), or $($rest)+);
+            value
+        }
+    };
+
+    ($s: expr, $p: literal as $t: ty) => {
+        $crate::deser_field::extract::<$t, _>(&$s, $p)
+    };
+
+}
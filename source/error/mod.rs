@@ -0,0 +1,677 @@
+//! Error types shared by the macros of the crate
+
+
+use std::{any::Any, fmt};
+
+
+/// Error of a single item of a sequence, along with the position of the item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndexedError<E> {
+    /// Zero-based position of the failed item.
+    pub index: usize,
+    /// Error of the failed item.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for IndexedError<E> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "item {}: {}", self.index, self.error)
+    }
+
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for IndexedError<E> {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+
+}
+
+
+/// Error of a single field of a struct, along with the name of the field.
+///
+/// Passed to the error handler of the [`crate::bind_struct!`] macro.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FieldError<E> {
+    /// Name of the failed field.
+    pub field: &'static str,
+    /// Error of the failed field.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for FieldError<E> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "field `{}`: {}", self.field, self.error)
+    }
+
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for FieldError<E> {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+
+}
+
+
+/// Names of the missing fields or steps, in order.
+///
+/// Displayed as `missing: host, port, tls_cert`. Passed to the error handler of
+/// the [`crate::require_fields!`] macro and yielded by the [`crate::try_all!`] macro
+/// with the named expressions. The [`FieldError`] of the [`crate::bind_struct!`] macro
+/// converts into it as well.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MissingFields(pub Vec<&'static str>);
+
+impl MissingFields {
+
+    /// Creates an empty list of the missing fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the name of a missing field.
+    pub fn push(&mut self, name: &'static str) {
+        self.0.push(name);
+    }
+
+    /// Tells whether no field is missing.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Unwraps the names of the missing fields.
+    pub fn into_inner(self) -> Vec<&'static str> {
+        self.0
+    }
+
+}
+
+impl<E> From<FieldError<E>> for MissingFields {
+
+    fn from(err: FieldError<E>) -> Self {
+        Self(vec![err.field])
+    }
+
+}
+
+impl fmt::Display for MissingFields {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing: {}", self.0.join(", "))
+    }
+
+}
+
+impl std::error::Error for MissingFields { }
+
+
+/// Error of an [`Option`] that is [`None`], naming the type of the expected value.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of the
+/// [`Strict`](crate::adapters::Strict) adapter, used instead of `()`.
+/// Unlike `()`, it is meaningful when logged or boxed into `dyn Error`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NoneError {
+    /// Name of the type of the expected value, as given by [`std::any::type_name`].
+    pub type_name: &'static str,
+}
+
+impl NoneError {
+
+    /// Creates the error for the expected value of type `T`.
+    pub fn new<T: ?Sized>() -> Self {
+        Self { type_name: std::any::type_name::<T>() }
+    }
+
+}
+
+impl fmt::Display for NoneError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected a value of type `{}`, found none", self.type_name)
+    }
+
+}
+
+impl std::error::Error for NoneError { }
+
+
+/// Error of a `bool` that is `false`.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of `bool`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct False;
+
+impl fmt::Display for False {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected `true`, found `false`")
+    }
+
+}
+
+impl std::error::Error for False { }
+
+
+/// Errors of all the failed alternatives, in the order of the alternatives.
+///
+/// Wraps the tuple of the errors, which may be of different types,
+/// implementing [`Display`](fmt::Display) and [`Error`](std::error::Error)
+/// for the tuples of up to 12 errors that implement those. Yielded by
+/// the [`crate::first_ok!`] macro if none of the alternatives succeeds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Errors<T>(pub T);
+
+impl<T> Errors<T> {
+
+    /// Unwraps the tuple of the errors.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+}
+
+
+macro_rules! impl_errors {
+
+    ($($t: ident)+) => {
+        impl<$($t: fmt::Display),+> fmt::Display for Errors<($($t,)+)> {
+
+            #[allow(non_snake_case)]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ($($t,)+) = &self.0;
+                f.write_str("all alternatives failed")?;
+                for (index, error) in [$($t as &dyn fmt::Display),+].into_iter().enumerate() {
+                    let separator = if index == 0 { ": " } else { "; " };
+                    write!(f, "{separator}{error}")?;
+                }
+                Ok(())
+            }
+
+        }
+
+        impl<$($t: std::error::Error),+> std::error::Error for Errors<($($t,)+)> { }
+    };
+
+}
+
+impl_errors!(A);
+impl_errors!(A B);
+impl_errors!(A B C);
+impl_errors!(A B C D);
+impl_errors!(A B C D E);
+impl_errors!(A B C D E F);
+impl_errors!(A B C D E F G);
+impl_errors!(A B C D E F G H);
+impl_errors!(A B C D E F G H I);
+impl_errors!(A B C D E F G H I J);
+impl_errors!(A B C D E F G H I J K);
+impl_errors!(A B C D E F G H I J K L);
+
+
+/// Value out of the expected range, along with the range.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of the
+/// [`Bounded`](crate::adapters::Bounded) and [`SliceGetMut`](crate::adapters::SliceGetMut) adapters.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OutOfRange<T, R> {
+    /// Value out of the range.
+    pub value: T,
+    /// Range the value is out of.
+    pub bounds: R,
+}
+
+impl<T: fmt::Debug, R: fmt::Debug> fmt::Display for OutOfRange<T, R> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is out of the range {:?}", self.value, self.bounds)
+    }
+
+}
+
+impl<T: fmt::Debug, R: fmt::Debug> std::error::Error for OutOfRange<T, R> { }
+
+
+/// Failed checked arithmetic operation, along with the operands.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of the
+/// [`CheckedAdd`](crate::adapters::CheckedAdd) and the other checked arithmetic adapters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ArithmeticError<T> {
+    /// Operator of the operation, such as `+`.
+    pub op: &'static str,
+    /// Left operand.
+    pub lhs: T,
+    /// Right operand.
+    pub rhs: T,
+}
+
+impl<T: fmt::Display> fmt::Display for ArithmeticError<T> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failure = if self.op == "/" { "divides by zero or overflows" } else { "overflows" };
+        write!(f, "`{} {} {}` {}", self.lhs, self.op, self.rhs, failure)
+    }
+
+}
+
+impl<T: fmt::Debug + fmt::Display> std::error::Error for ArithmeticError<T> { }
+
+
+/// Path that failed a filesystem check, along with the I/O error of the check.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of the
+/// [`ExistingFile`](crate::adapters::ExistingFile), [`ExistingDir`](crate::adapters::ExistingDir)
+/// and [`ReadablePath`](crate::adapters::ReadablePath) adapters.
+#[derive(Debug)]
+pub struct PathError {
+    /// Path as it was provided, before the canonicalization.
+    pub path: std::path::PathBuf,
+    /// Error of the failed check.
+    pub error: std::io::Error,
+}
+
+impl fmt::Display for PathError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.error)
+    }
+
+}
+
+impl std::error::Error for PathError {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+
+}
+
+
+/// Child process that hasn't exited, or the error of checking whether it has.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of the
+/// [`Finished`](crate::adapters::Finished) adapter.
+#[derive(Debug)]
+pub enum NotFinished {
+    /// The process is still running.
+    Running,
+    /// Checking the process failed.
+    Failed(std::io::Error),
+}
+
+impl fmt::Display for NotFinished {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Running => write!(f, "the process is still running"),
+            Self::Failed(err) => write!(f, "failed to check the process: {}", err),
+        }
+    }
+
+}
+
+impl std::error::Error for NotFinished {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Running => None,
+            Self::Failed(err) => Some(err),
+        }
+    }
+
+}
+
+
+/// Future that isn't ready yet, or the error it completed with.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of the
+/// `TryNowOrNever` adapter of the `futures` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NotReady<E> {
+    /// The future is still pending.
+    Pending,
+    /// The future completed with the error.
+    Failed(E),
+}
+
+impl<E: fmt::Display> fmt::Display for NotReady<E> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => write!(f, "the future is still pending"),
+            Self::Failed(err) => write!(f, "the future failed: {}", err),
+        }
+    }
+
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for NotReady<E> {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Pending => None,
+            Self::Failed(err) => Some(err),
+        }
+    }
+
+}
+
+
+/// Failed query that returns at most one row, along with the query.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of the
+/// [`QueryOne`](crate::adapters::QueryOne) adapter.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct QueryError<E> {
+    /// Query that failed.
+    pub query: String,
+    /// Error of the query, or [`None`] if the query returned no rows.
+    pub error: Option<E>,
+}
+
+impl<E: fmt::Display> fmt::Display for QueryError<E> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "query `{}`: {}", self.query, error),
+            None => write!(f, "query `{}` returned no rows", self.query),
+        }
+    }
+
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for QueryError<E> {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.as_ref().map(|error| error as _)
+    }
+
+}
+
+
+/// Error of getting the value of a row column, along with the name of the column.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of the `ColumnAs`
+/// and `TryGetAs` adapters of the `rusqlite` and `sqlx` features.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColumnError<E> {
+    /// Name of the column.
+    pub column: String,
+    /// Error of getting the value.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for ColumnError<E> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "column `{}`: {}", self.column, self.error)
+    }
+
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ColumnError<E> {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+
+}
+
+
+/// Missing or invalid command line argument, along with the name of the argument.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of the `ArgOne`
+/// and `ArgMany` adapters of the `clap` feature.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ArgError<E> {
+    /// Name of the argument.
+    pub arg: String,
+    /// Error of getting the argument, or [`None`] if the argument is missing.
+    pub error: Option<E>,
+}
+
+impl<E: fmt::Display> fmt::Display for ArgError<E> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "argument `{}`: {}", self.arg, error),
+            None => write!(f, "argument `{}` is missing", self.arg),
+        }
+    }
+
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ArgError<E> {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.as_ref().map(|error| error as _)
+    }
+
+}
+
+
+/// Unsuccessful status of an HTTP response, along with the body of the response.
+///
+/// The [`IntoResult::Error`](crate::bind::IntoResult::Error) of the `SuccessOf`
+/// adapter of the `http` feature.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StatusError<B> {
+    /// Status code of the response.
+    pub status: u16,
+    /// Body of the response.
+    pub body: B,
+}
+
+impl<B> fmt::Display for StatusError<B> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsuccessful status {}", self.status)
+    }
+
+}
+
+impl<B: fmt::Debug> std::error::Error for StatusError<B> { }
+
+
+/// Poisoned lock among the ones acquired at once, along with its position.
+///
+/// Passed to the error handler of the [`crate::bind_locks!`] macro.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PoisonedLock {
+    /// Zero-based position of the poisoned lock in the list of the locks.
+    pub index: usize,
+}
+
+impl fmt::Display for PoisonedLock {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "lock {} is poisoned", self.index)
+    }
+
+}
+
+impl std::error::Error for PoisonedLock { }
+
+
+/// Event that the state machine has no transition for in its current state.
+///
+/// Returned by the `step` method generated by the [`crate::state_machine!`] macro.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvalidTransition<S, E> {
+    /// State that the machine was and remains in.
+    pub state: S,
+    /// Event that has no transition from the state.
+    pub event: E,
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> fmt::Display for InvalidTransition<S, E> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no transition from state {:?} on event {:?}", self.state, self.event)
+    }
+
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> std::error::Error for InvalidTransition<S, E> { }
+
+
+/// Error produced by the [`crate::unwrap_chain!`] macro when a step of the chain is [`None`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MissingLink {
+    /// Zero-based index of the step that is [`None`].
+    pub step: usize,
+    /// Stringified chain up to and excluding the `?` of the step that is [`None`].
+    pub path: &'static str,
+}
+
+impl fmt::Display for MissingLink {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is none", self.path)
+    }
+
+}
+
+impl std::error::Error for MissingLink { }
+
+
+/// Error produced by the [`crate::validate!`] macro when the value fails the check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Invalid<T> {
+    /// Value that failed the check.
+    pub value: T,
+    /// Stringified range or predicate the value was checked against.
+    pub check: &'static str,
+}
+
+impl<T: fmt::Debug> fmt::Display for Invalid<T> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} fails the check `{}`", self.value, self.check)
+    }
+
+}
+
+impl<T: fmt::Debug> std::error::Error for Invalid<T> { }
+
+
+/// Error produced by the [`crate::catch_panic!`] macro when the expression panics.
+///
+/// Holds the panic payload and provides access to the panic message if the payload is
+/// a string, which is the case for panics raised by [`panic!`] and the like.
+pub struct Panic {
+    payload: Box<dyn Any + Send>,
+}
+
+impl Panic {
+
+    /// Returns the panic message if the payload is a string.
+    pub fn message(&self) -> Option<&str> {
+        self.payload
+            .downcast_ref::<&'static str>()
+            .copied()
+            .or_else(|| self.payload.downcast_ref::<String>().map(String::as_str))
+    }
+
+    /// Returns the panic payload, e.g. to pass it to [`std::panic::resume_unwind`].
+    pub fn into_payload(self) -> Box<dyn Any + Send> {
+        self.payload
+    }
+
+}
+
+impl From<Box<dyn Any + Send>> for Panic {
+
+    fn from(payload: Box<dyn Any + Send>) -> Self {
+        Self { payload }
+    }
+
+}
+
+impl fmt::Debug for Panic {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Panic").field("message", &self.message()).finish_non_exhaustive()
+    }
+
+}
+
+impl fmt::Display for Panic {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.message() {
+            Some(message) => write!(f, "panicked: {message}"),
+            None => f.write_str("panicked with a non-string payload"),
+        }
+    }
+
+}
+
+impl std::error::Error for Panic { }
+
+
+/// Error produced by the [`crate::compose!`] macro when a step of the pipeline fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StepError<E> {
+    /// Stringified expression of the failed step.
+    pub step: &'static str,
+    /// Error of the failed step.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for StepError<E> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "step `{}`: {}", self.step, self.error)
+    }
+
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for StepError<E> {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+
+}
+
+
+/// Error produced by the [`crate::deser_field!`] macro when the field is missing
+/// or can't be deserialized.
+#[cfg(feature = "json")]
+#[derive(Debug)]
+pub struct DeserError {
+    /// Dotted path of the field.
+    pub path: &'static str,
+    /// Name of the type the field was deserialized to, as given by [`std::any::type_name`].
+    pub type_name: &'static str,
+    /// Error of the deserialization, or [`None`] if the field is missing.
+    pub error: Option<serde_json::Error>,
+}
+
+#[cfg(feature = "json")]
+impl fmt::Display for DeserError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "`{}` as `{}`: {}", self.path, self.type_name, error),
+            None => write!(f, "`{}` is missing", self.path),
+        }
+    }
+
+}
+
+#[cfg(feature = "json")]
+impl std::error::Error for DeserError {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.as_ref().map(|error| error as _)
+    }
+
+}
@@ -0,0 +1,107 @@
+//! The [`crate::expect_ok!`] and [`crate::expect_some!`] macros
+
+
+/// Yields the unwrapped value or panics with the expression and the error
+///
+/// [Tests](crate::bind::IntoResult) whether the value of the provided expression can be unwrapped
+/// and yields the unwrapped value if it can. Otherwise, panics with the message including
+/// the location of the invocation, the expression and the [`Debug`](std::fmt::Debug)
+/// representation of the error.
+///
+/// Meant for tests, where unlike [`Result::unwrap`] it works with any
+/// [`IntoResult`](crate::bind::IntoResult) type and tells which expression failed.
+///
+/// # Syntax
+///
+/// ```text
+/// expect_ok!(<value-expr>)
+/// ```
+///
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value, the error of which implements [`Debug`](std::fmt::Debug).
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::expect_ok;
+/// #
+/// let x = expect_ok!("42".parse::<i32>());
+/// assert_eq!(x, 42);
+/// ```
+///
+#[cfg_attr(feature = "impl-result", doc = "```should_panic")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::expect_ok;
+/// #
+/// // panics with '`"x".parse::<i32>()` failed at src/main.rs:4:1: ParseIntError { kind: InvalidDigit }'
+/// expect_ok!("x".parse::<i32>());
+/// ```
+#[macro_export]
+macro_rules! expect_ok {
+
+    ($e: expr) => {
+        match {
+            use $crate::bind::IntoResult;
+            $e.into_result()
+        } {
+            Ok(value) => value,
+            Err(err) => ::core::panic!(
+                "`{}` failed at {}:{}:{}: {:?}",
+                ::core::stringify!($e), ::core::file!(), ::core::line!(), ::core::column!(), err,
+            ),
+        }
+    };
+
+}
+
+
+/// Yields the unwrapped value or panics with the expression
+///
+/// Works like the [`crate::expect_ok!`] macro, but the panic message doesn't include the error,
+/// which thus doesn't have to implement [`Debug`](std::fmt::Debug). Suits [`Option`] and
+/// other [`IntoResult`](crate::bind::IntoResult) types with no meaningful error.
+///
+/// # Syntax
+///
+/// ```text
+/// expect_some!(<value-expr>)
+/// ```
+///
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::expect_some;
+/// #
+/// let x = expect_some!([41, 42].iter().max());
+/// assert_eq!(*x, 42);
+/// ```
+///
+#[cfg_attr(feature = "impl-option", doc = "```should_panic")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::expect_some;
+/// #
+/// // panics with '`None::<i32>` has no value at src/main.rs:4:1'
+/// expect_some!(None::<i32>);
+/// ```
+#[macro_export]
+macro_rules! expect_some {
+
+    ($e: expr) => {
+        match {
+            use $crate::bind::IntoResult;
+            $e.into_result()
+        } {
+            Ok(value) => value,
+            Err(_) => ::core::panic!(
+                "`{}` has no value at {}:{}:{}",
+                ::core::stringify!($e), ::core::file!(), ::core::line!(), ::core::column!(),
+            ),
+        }
+    };
+
+}
@@ -0,0 +1,56 @@
+//! The [`crate::fallback!`] macro
+
+
+/// Yields the first unwrappable value among the alternatives or the default value
+///
+/// Evaluates the provided expressions one by one, in order, until the value of one
+/// [can be unwrapped](crate::bind::IntoResult), and yields the unwrapped value.
+/// The remaining expressions are not evaluated. Yields the value of the `else` expression
+/// if none of the values can be unwrapped. The errors are discarded.
+///
+/// Unlike the [`crate::bind!`] macro, can be used in any expression position.
+/// To keep the errors, use the [`crate::first_ok!`] macro instead.
+///
+/// # Syntax
+///
+/// ```text
+/// fallback!([<value-expr>,]+ else <default>)
+/// ```
+///
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+/// - `<default>` — expression yielded if none of the values can be unwrapped.
+///
+/// # Examples
+///
+#[cfg_attr(all(feature = "impl-option", feature = "impl-result"), doc = "```")]
+#[cfg_attr(not(all(feature = "impl-option", feature = "impl-result")), doc = "```ignore")]
+/// # use el_macro::fallback;
+/// #
+/// let from_env = || None;
+/// let from_file = || "42".parse::<u16>();
+///
+/// let port = fallback!(from_env(), from_file(), else 8080);
+/// assert_eq!(port, 42);
+///
+/// let port = fallback!(from_env(), "x".parse::<u16>(), else 8080);
+/// assert_eq!(port, 8080);
+/// ```
+#[macro_export]
+macro_rules! fallback {
+
+    (else $d: expr $(,)?) => {
+        $d
+    };
+
+    ($e: expr, $($rest: tt)+) => {
+        match {
+            use $crate::bind::IntoResult;
+            $e.into_result()
+        } {
+            Ok(value) => value,
+            Err(_) => $crate::fallback!($($rest)+),
+        }
+    };
+
+}
@@ -0,0 +1,85 @@
+//! The [`crate::first_ok!`] macro
+
+
+/// Yields the first unwrappable value among the alternatives or all of their errors
+///
+/// Evaluates the provided expressions one by one, in order, until the value of one
+/// [can be unwrapped](crate::bind::IntoResult). The remaining expressions are not evaluated.
+/// Yields [`Ok`] with the unwrapped value, or [`Err`] with [`Errors`](crate::error::Errors)
+/// wrapping the tuple of all the errors in the order of the expressions if none of the values
+/// can be unwrapped.
+///
+/// All the values must be of the same type, while the errors may be of different types.
+///
+/// # Syntax
+///
+/// ```text
+/// first_ok!(<value-expr> [, <value-expr>]*)
+/// ```
+///
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+///
+/// # Examples
+///
+/// Basic usage:
+#[cfg_attr(all(feature = "impl-option", feature = "impl-result"), doc = "```")]
+#[cfg_attr(not(all(feature = "impl-option", feature = "impl-result")), doc = "```ignore")]
+/// # use el_macro::{bind, first_ok};
+/// #
+/// let from_cache = || None;
+/// let from_disk = || "42".parse::<i32>();
+/// let from_network = || -> Result<i32, &str> { unreachable!() };
+///
+/// let x = first_ok!(from_cache(), from_disk(), from_network());
+/// assert_eq!(x, Ok(42));
+///
+/// bind!(x = first_ok!(from_cache(), from_disk()), or return);
+/// assert_eq!(x, 42);
+/// ```
+///
+/// Getting all the errors:
+#[cfg_attr(all(feature = "impl-option", feature = "impl-result"), doc = "```")]
+#[cfg_attr(not(all(feature = "impl-option", feature = "impl-result")), doc = "```ignore")]
+/// # use el_macro::first_ok;
+/// #
+/// let x = first_ok!(None::<i32>, "x".parse::<i32>(), Err::<i32, _>("offline"));
+/// let (none, parse_err, net_err) = x.unwrap_err().into_inner();
+/// assert_eq!(none, ());
+/// assert_eq!(parse_err.to_string(), "invalid digit found in string");
+/// assert_eq!(net_err, "offline");
+/// ```
+///
+/// Reporting all the errors at once:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::first_ok;
+/// #
+/// let x = first_ok!("x".parse::<i32>(), Err::<i32, _>("offline"));
+/// assert_eq!(
+///     x.unwrap_err().to_string(),
+///     "all alternatives failed: invalid digit found in string; offline",
+/// );
+/// ```
+#[macro_export]
+macro_rules! first_ok {
+
+    (@next [$($err: ident)*] $e: expr $(, $rest: expr)*) => {
+        match {
+            use $crate::bind::IntoResult;
+            $e.into_result()
+        } {
+            Ok(value) => Ok(value),
+            Err(err) => $crate::first_ok!(@next [$($err)* err] $($rest),*),
+        }
+    };
+
+    (@next [$($err: ident)*]) => {
+        Err($crate::error::Errors(($($err,)*)))
+    };
+
+    ($($e: expr),+ $(,)?) => {
+        $crate::first_ok!(@next [] $($e),+)
+    };
+
+}
@@ -0,0 +1,85 @@
+//! The [`crate::fold_ok!`] macro
+
+
+/// Folds the items of an iterator with a fallible step and binds the final accumulator
+///
+/// Initializes the accumulator variable with the provided value and, for each item
+/// of the provided [`IntoIterator`], evaluates the step expression, which may refer
+/// to the accumulator and the variables bound by the pattern, and [tests](crate::bind::IntoResult)
+/// whether its value can be unwrapped. Sets the accumulator to the unwrapped value if it can,
+/// otherwise stops folding, calls the optional error handler and evaluates the execution flow
+/// control expression. Binds the accumulator variable to its final value if all the steps succeed.
+///
+/// # Syntax
+///
+/// ```text
+/// fold_ok!([mut] <var-name> = <init-expr>, for <pattern> in <items-expr>, <step-expr>,
+///     or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `mut`, `<err-handler>`, `<flow-ctl>` — same as for the [`crate::bind!`] macro.
+/// - `<var-name>` — name of the accumulator variable.
+/// - `<init-expr>` — initial value of the accumulator.
+/// - `<pattern>`, `<items-expr>` — same as for the `for` loop.
+/// - `<step-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   the next value of the accumulator.
+///
+/// # Examples
+///
+/// Basic usage:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::fold_ok;
+/// #
+/// let lines = ["1", "41"];
+///
+/// fold_ok!(total = 0, for line in lines, line.parse::<i32>().map(|n| total + n), or return);
+/// assert_eq!(total, 42);
+/// ```
+///
+/// Handling error values:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::fold_ok;
+/// #
+/// let lines = ["1", "x", "41"];
+///
+/// // prints 'invalid digit found in string' and returns
+/// fold_ok!(total = 0, for line in lines, line.parse::<i32>().map(|n| total + n),
+///     or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! fold_ok {
+
+    (@fold $n: ident = $a: expr, for $p: pat in $i: expr, $s: expr) => {
+        'fold: {
+            use $crate::bind::IntoResult;
+            let mut $n = $a;
+            for $p in $i {
+                match $s.into_result() {
+                    Ok(value) => $n = value,
+                    Err(err) => break 'fold Err(err),
+                }
+            }
+            Ok($n)
+        }
+    };
+
+    ($n: ident = $a: expr, for $p: pat in $i: expr, $s: expr, or $h: expr, $f: expr) => {
+        $crate::bind!($n = $crate::fold_ok!(@fold $n = $a, for $p in $i, $s), or $h, $f)
+    };
+
+    ($n: ident = $a: expr, for $p: pat in $i: expr, $s: expr, or $f: expr) => {
+        $crate::bind!($n = $crate::fold_ok!(@fold $n = $a, for $p in $i, $s), or $f)
+    };
+
+    (mut $n: ident = $a: expr, for $p: pat in $i: expr, $s: expr, or $h: expr, $f: expr) => {
+        $crate::bind!(mut $n = $crate::fold_ok!(@fold $n = $a, for $p in $i, $s), or $h, $f)
+    };
+
+    (mut $n: ident = $a: expr, for $p: pat in $i: expr, $s: expr, or $f: expr) => {
+        $crate::bind!(mut $n = $crate::fold_ok!(@fold $n = $a, for $p in $i, $s), or $f)
+    };
+
+}
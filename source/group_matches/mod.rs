@@ -0,0 +1,87 @@
+//! The [`crate::group_matches!`] macro
+
+
+/// Groups the items of an iterator that match the pattern into a map by key
+///
+/// Matches each item of the provided [`IntoIterator`] against the pattern and, if the item
+/// matches the pattern and the optional match guard, evaluates the key expression
+/// and pushes the item into the [`Vec`] of the items with the same key. Skips the items
+/// that don't match. Yields the map from the keys to the [`Vec`]s of the items,
+/// in the order of the iterator.
+///
+/// The items are matched by reference, so the variables bound by the pattern are references
+/// to the parts of the item. The key is converted with [`ToOwned::to_owned`] to not borrow
+/// from the item.
+///
+/// # Syntax
+///
+/// ```text
+/// group_matches!(<items-expr>, <pattern> [if <match-guard>] => <key> [, into <map-type>])
+/// ```
+///
+/// - `<items-expr>` — [`IntoIterator`] of items to be matched against `<pattern>`.
+/// - `<pattern>` — pattern that is matched against.
+/// - `<match-guard>` — optional match guard that further restricts the match.
+/// - `<key>` — expression the bound variables of a matching item are mapped into its key with.
+/// - `<map-type>` — optional type of the map, with `entry` method like the one of
+///   [`HashMap`](std::collections::HashMap) and [`BTreeMap`](std::collections::BTreeMap),
+///   [`HashMap`](std::collections::HashMap) if not specified.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use el_macro::group_matches;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// enum Event { Key { code: char, down: bool }, Click }
+///
+/// let events = [
+///     Event::Key { code: 'a', down: true },
+///     Event::Click,
+///     Event::Key { code: 'b', down: true },
+///     Event::Key { code: 'a', down: false },
+/// ];
+///
+/// let by_code = group_matches!(events, Event::Key { code, .. } => code);
+/// assert_eq!(by_code.len(), 2);
+/// assert_eq!(by_code[&'a'], [Event::Key { code: 'a', down: true }, Event::Key { code: 'a', down: false }]);
+/// ```
+///
+/// Usage with match guard and ordered map:
+/// ```
+/// # use el_macro::group_matches;
+/// # use std::collections::BTreeMap;
+/// #
+/// let words = ["apple", "fig", "avocado", "banana", "kiwi"];
+///
+/// let by_initial = group_matches!(
+///     words, w if w.len() > 3 => &w[..1], into BTreeMap<_, _>
+/// );
+/// assert_eq!(by_initial.keys().collect::<Vec<_>>(), ["a", "b", "k"]);
+/// assert_eq!(by_initial["a"], ["apple", "avocado"]);
+/// ```
+#[macro_export]
+macro_rules! group_matches {
+
+    ($i: expr, $p: pat $(if $c: expr)? => $k: expr, into $t: ty) => {
+        {
+            let mut groups: $t = ::core::default::Default::default();
+            for item in $i {
+                let key = match &item {
+                    $p $(if $c)? => ::core::option::Option::Some(($k).to_owned()),
+                    _ => ::core::option::Option::None,
+                };
+                if let ::core::option::Option::Some(key) = key {
+                    groups.entry(key).or_insert_with(::std::vec::Vec::new).push(item);
+                }
+            }
+            groups
+        }
+    };
+
+    ($i: expr, $p: pat $(if $c: expr)? => $k: expr) => {
+        $crate::group_matches!($i, $p $(if $c)? => $k, into ::std::collections::HashMap<_, _>)
+    };
+
+}
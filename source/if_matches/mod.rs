@@ -1,4 +1,4 @@
-//! The [`crate::if_matches!`] macro
+//! The [`crate::if_matches!`], [`crate::if_not_matches!`] and [`crate::match_or_keep!`] macros
 
 
 /// Maps pattern's bound variables to [`Some`] if the provided expression matches the pattern.
@@ -11,12 +11,19 @@
 /// # Syntax
 ///
 /// ```text
-/// if_matches!(<expression>, <pattern> [if <match-guard>] => <mapping-body>)
+/// if_matches!(<expression>, [<attrs>] <pattern> [if <match-guard>] => <mapping-body>)
+/// if_matches!(<expression>, [<attrs>] <pattern> if let <guard-pattern> = <guard-expr> => <mapping-body>)
 /// ```
 ///
-/// - `<expression>` — expression to be evaluated against `<pattern>`.
+/// - `<expression>` — expression to be evaluated against `<pattern>`. A reference, such as
+///   `&self.state`, is matched without moving out of the value, with `<pattern>` binding
+///   the references to its parts.
+/// - `<attrs>` — optional attributes, such as `#[cfg(...)]`, applied to the generated match arm.
 /// - `<pattern>` — pattern that is matched against.
 /// - `<match-guard>` — optional match guard. that further restricts the match.
+/// - `<guard-pattern>`, `<guard-expr>` — `if let` guard, which isn't supported by stable Rust
+///   in `match`. Restricts the match to the cases when `<guard-expr>` matches `<guard-pattern>`,
+///   whose bound variables are available within `<mapping-body>` as well.
 /// - `<mapping-body>` — body of the closure that defined how to map `<pattern>`'s
 ///   bound variables into a [`Some`] value.
 ///
@@ -51,14 +58,191 @@
 /// let per_bin = if_matches!((vol, bins), (Some(v), Some(b)) if b != 0 => v / b);
 /// assert!(per_bin.is_none());
 /// ```
+///
+/// Usage with attributes:
+/// ```
+/// # use el_macro::if_matches;
+/// #
+/// let x: Option<i32> = if_matches!(Some(42), #[cfg(any())] Some(x) => x);
+/// assert_eq!(x, None);
+///
+/// let x = if_matches!(Some(42), #[cfg(all())] Some(x) => x);
+/// assert_eq!(x, Some(42));
+/// ```
+///
+/// Usage with `if let` guard:
+/// ```
+/// # use el_macro::if_matches;
+/// #
+/// struct Request { path: &'static str, auth: Option<&'static str> }
+///
+/// let token = |req: Request| if_matches!(req,
+///     Request { path: "/admin", auth } if let Some(token) = auth => token.len());
+///
+/// assert_eq!(token(Request { path: "/admin", auth: Some("secret") }), Some(6));
+/// assert_eq!(token(Request { path: "/admin", auth: None }), None);
+/// assert_eq!(token(Request { path: "/", auth: Some("secret") }), None);
+/// ```
+///
+/// Matching borrowed values:
+/// ```
+/// # use el_macro::if_matches;
+/// #
+/// enum State { Running { pid: u32, cmd: String }, Stopped }
+/// struct Task { state: State }
+///
+/// impl Task {
+///     fn cmd_len(&self) -> Option<usize> {
+///         if_matches!(&self.state, State::Running { cmd, .. } => cmd.len())
+///     }
+/// }
+///
+/// let mut task = Task { state: State::Running { pid: 42, cmd: "sleep".into() } };
+/// assert_eq!(task.cmd_len(), Some(5));
+///
+/// if_matches!(&mut task.state, State::Running { pid, .. } => *pid += 1);
+/// assert!(matches!(task.state, State::Running { pid: 43, .. }));
+///
+/// task.state = State::Stopped;
+/// assert_eq!(task.cmd_len(), None);
+/// ```
 #[macro_export]
 macro_rules! if_matches {
 
-    ($e: expr, $p: pat $(if $c:expr)? => $m: expr) => {
+    ($e: expr, $(#[$a: meta])* $p: pat if let $gp: pat = $ge: expr => $m: expr) => {
         match $e {
+            $(#[$a])*
+            $p => match $ge {
+                $gp => Some((|| $m)()),
+                _ => None,
+            },
+            _ => None,
+        }
+    };
+
+    ($e: expr, $(#[$a: meta])* $p: pat $(if $c:expr)? => $m: expr) => {
+        match $e {
+            $(#[$a])*
             $p $(if $c)? => Some((|| $m)()),
             _ => None,
         }
     };
 
 }
+
+
+/// Yields [`Some`] if the provided expression does not match the pattern.
+///
+/// Evaluates the provided expression against the pattern and yields [`None`] if the expression
+/// matches the pattern and the optional match guard evaluates to `true`. Otherwise, yields
+/// [`Some`] with the value of the expression or, if provided, the value of the body.
+///
+/// Since the expression is not moved unless it matches the pattern by value, the body may refer
+/// to the original value if the expression is a place, such as a variable.
+///
+/// # Syntax
+///
+/// ```text
+/// if_not_matches!(<expression>, <pattern> [if <match-guard>] [=> <body>])
+/// ```
+///
+/// - `<expression>` — expression to be evaluated against `<pattern>`.
+/// - `<pattern>` — pattern that is matched against.
+/// - `<match-guard>` — optional match guard that further restricts the match.
+/// - `<body>` — optional body of the closure, the value of which is yielded in [`Some`]
+///   if `<expression>` doesn't match, as with the [`crate::if_matches!`] macro.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use el_macro::if_not_matches;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// enum Status { Ok, Cached, Failed(u16) }
+///
+/// let status = Status::Failed(503);
+/// let anomaly = if_not_matches!(status, Status::Ok | Status::Cached);
+/// assert_eq!(anomaly, Some(Status::Failed(503)));
+///
+/// let anomaly = if_not_matches!(Status::Cached, Status::Ok | Status::Cached);
+/// assert_eq!(anomaly, None);
+/// ```
+///
+/// Usage with body:
+/// ```
+/// # use el_macro::if_not_matches;
+/// #
+/// let mut anomalies = Vec::new();
+///
+/// for code in [200, 304, 503] {
+///     if_not_matches!(code, 200..=299 | 304 => anomalies.push(code));
+/// }
+/// assert_eq!(anomalies, [503]);
+///
+/// let odd = if_not_matches!(42, n if n % 2 == 0 => "odd");
+/// assert_eq!(odd, None);
+/// ```
+#[macro_export]
+macro_rules! if_not_matches {
+
+    ($e: expr, $p: pat $(if $c: expr)? => $m: expr) => {
+        match $e {
+            $p $(if $c)? => None,
+            _ => Some((|| $m)()),
+        }
+    };
+
+    ($e: expr, $p: pat $(if $c: expr)?) => {
+        match $e {
+            $p $(if $c)? => None,
+            value => Some(value),
+        }
+    };
+
+}
+
+
+/// Maps pattern's bound variables to [`Ok`] or gives the value back in [`Err`]
+///
+/// Works like the [`crate::if_matches!`] macro, but yields [`Err`] with the value
+/// of the provided expression instead of [`None`] if the expression does not match
+/// the pattern or the optional match guard evaluates to `false`. Keeps the ownership of
+/// the values of the types that can't be copied or cloned on the non-matching path.
+///
+/// # Syntax
+///
+/// ```text
+/// match_or_keep!(<expression>, <pattern> [if <match-guard>] => <mapping-body>)
+/// ```
+///
+/// - `<expression>`, `<pattern>`, `<match-guard>`, `<mapping-body>` — same as for
+///   the [`crate::if_matches!`] macro.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::match_or_keep;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// enum Message { Text(String), Binary(Vec<u8>) }
+///
+/// let message = Message::Binary(vec![4, 2]);
+/// let text = match_or_keep!(message, Message::Text(text) => text.len());
+/// assert_eq!(text, Err(Message::Binary(vec![4, 2])));
+///
+/// let message = text.unwrap_err();
+/// let len = match_or_keep!(message, Message::Binary(bytes) if !bytes.is_empty() => bytes.len());
+/// assert_eq!(len, Ok(2));
+/// ```
+#[macro_export]
+macro_rules! match_or_keep {
+
+    ($e: expr, $p: pat $(if $c: expr)? => $m: expr) => {
+        match $e {
+            $p $(if $c)? => Ok((|| $m)()),
+            value => Err(value),
+        }
+    };
+
+}
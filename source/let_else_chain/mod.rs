@@ -0,0 +1,63 @@
+//! The [`crate::let_else_chain!`] macro
+
+
+/// Performs several refutable bindings sharing the flow control expression
+///
+/// Binds the value of each provided expression to the respective pattern in order,
+/// as a `let`-`else` statement would. If any of the values doesn't match its pattern,
+/// evaluates the shared execution flow control expression, skipping the remaining bindings.
+/// The variables bound by a pattern are available in the later expressions.
+///
+/// # Syntax
+///
+/// ```text
+/// let_else_chain! {
+///     [<pattern> = <expression>;]+
+///     else <flow-ctl>[;]
+/// }
+/// ```
+///
+/// - `<pattern>` — refutable pattern the value of `<expression>` is bound to.
+/// - `<expression>` — expression matched against `<pattern>`.
+/// - `<flow-ctl>` — diverging expression used to control the execution flow in a case
+///   when any of the values doesn't match its pattern.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::let_else_chain;
+/// #
+/// let x = Some(40);
+/// let y = Ok::<_, ()>(2);
+///
+/// let_else_chain! {
+///     Some(a) = x;
+///     Ok(b) = y;
+///     [c, ..] = [a + b, 0][..];
+///     else return;
+/// }
+/// assert_eq!(c, 42);
+///
+/// let_else_chain! {
+///     Some(a) = x;
+///     Some(b) = None::<i32>;
+///     else return;
+/// }
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! let_else_chain {
+
+    (@collect [$(($p: pat = $e: expr))*] else $f: expr $(;)?) => {
+        $(let $p = ($e) else { $f };)*
+    };
+
+    (@collect [$($b: tt)*] $p: pat = $e: expr; $($rest: tt)+) => {
+        $crate::let_else_chain!(@collect [$($b)* ($p = $e)] $($rest)+);
+    };
+
+    ($($t: tt)+) => {
+        $crate::let_else_chain!(@collect [] $($t)+);
+    };
+
+}
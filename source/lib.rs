@@ -1,2 +1,126 @@
+#![cfg_attr(feature = "nightly-try", feature(try_trait_v2))]
+#![cfg_attr(
+    all(test, feature = "nightly-coroutine"),
+    feature(coroutines, coroutine_trait, stmt_expr_attributes),
+)]
+
+
+pub mod adapters;
+mod attempt;
 pub mod bind;
+mod bind_all;
+mod bind_locks;
+mod bind_scope;
+mod bind_static;
+mod bind_struct;
+pub mod catch_panic;
+mod cfg_bind;
+mod chain_matches;
+mod collect_matches;
+mod collect_ok;
+pub mod compose;
+mod cond;
+mod count_matches;
+mod dbg_bind;
+#[cfg(feature = "json")]
+pub mod deser_field;
+pub mod error;
+mod expect;
+mod fallback;
+mod first_ok;
+mod fold_ok;
+mod group_matches;
 mod if_matches;
+mod let_else_chain;
+mod loop_matches;
+mod match_downcast;
+mod ok_or;
+mod partition_matches;
+mod peek_matches;
+mod pipe;
+mod recv_matches;
+mod require_fields;
+pub mod retry;
+mod skip_until_matches;
+mod state_machine;
+mod take_while_matches;
+mod tap;
+mod try_all;
+mod try_zip;
+pub mod unwrap_chain;
+pub mod validate;
+mod visit_matches;
+mod with;
+
+#[cfg(feature = "proc")]
+pub mod proc;
+
+#[cfg(feature = "stats")]
+pub mod stats;
+
+#[cfg(feature = "test-util")]
+pub mod test_support;
+
+#[cfg(feature = "proc")]
+pub use el_macro_proc::{bind_handler, or_return};
+
+/// The macros under the names prefixed with `el_`, such as `el_bind!`, for the crates
+/// that use other macros with the same names. Available with the `aliases` feature.
+#[cfg(feature = "aliases")]
+pub use crate::{
+    attempt as el_attempt,
+    bind as el_bind,
+    bind_all as el_bind_all,
+    bind_locks as el_bind_locks,
+    bind_scope as el_bind_scope,
+    bind_static as el_bind_static,
+    bind_struct as el_bind_struct,
+    catch_panic as el_catch_panic,
+    cfg_bind as el_cfg_bind,
+    chain_matches as el_chain_matches,
+    collect_matches as el_collect_matches,
+    collect_ok as el_collect_ok,
+    compose as el_compose,
+    cond as el_cond,
+    count_matches as el_count_matches,
+    dbg_bind as el_dbg_bind,
+    expect_ok as el_expect_ok,
+    expect_some as el_expect_some,
+    fallback as el_fallback,
+    first_ok as el_first_ok,
+    fold_ok as el_fold_ok,
+    group_matches as el_group_matches,
+    if_matches as el_if_matches,
+    if_not_matches as el_if_not_matches,
+    inspect as el_inspect,
+    let_else_chain as el_let_else_chain,
+    loop_matches as el_loop_matches,
+    match_downcast as el_match_downcast,
+    match_or_keep as el_match_or_keep,
+    ok_or_break as el_ok_or_break,
+    ok_or_continue as el_ok_or_continue,
+    on_err as el_on_err,
+    partition_matches as el_partition_matches,
+    peek_matches as el_peek_matches,
+    pipe as el_pipe,
+    recv_matches as el_recv_matches,
+    require_fields as el_require_fields,
+    retry as el_retry,
+    skip_until_matches as el_skip_until_matches,
+    state_machine as el_state_machine,
+    take_while_matches as el_take_while_matches,
+    tap as el_tap,
+    try_all as el_try_all,
+    try_join_all_errors as el_try_join_all_errors,
+    try_zip as el_try_zip,
+    unwrap_chain as el_unwrap_chain,
+    validate as el_validate,
+    visit_matches as el_visit_matches,
+    with as el_with,
+};
+
+#[cfg(all(feature = "aliases", feature = "json"))]
+pub use crate::deser_field as el_deser_field;
+
+#[doc(hidden)]
+pub mod private;
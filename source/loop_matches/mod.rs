@@ -0,0 +1,59 @@
+//! The [`crate::loop_matches!`] macro
+
+
+/// Repeatedly evaluates the source expression and dispatches its value across the match arms
+///
+/// Evaluates the provided source expression in a loop and matches each value against the arms,
+/// evaluating the body of the matching arm. The loop runs until an arm exits it with `break`,
+/// optionally with a value, which then is the value of the macro. `continue` within the arms
+/// proceeds to the next value.
+///
+/// Condenses the `loop { match … { … } }` skeleton of event loops and channel consumers.
+///
+/// # Syntax
+///
+/// ```text
+/// loop_matches!(<source-expr>, {
+///     <match-arms>
+/// })
+/// ```
+///
+/// - `<source-expr>` — expression evaluated on each iteration to get the value to dispatch.
+/// - `<match-arms>` — arms of a `match` expression, which must cover all the values.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::loop_matches;
+/// # use std::sync::mpsc;
+/// #
+/// enum Event { Key(char), Resize(u32, u32), Quit }
+///
+/// let (tx, rx) = mpsc::channel();
+/// for event in [Event::Key('h'), Event::Resize(80, 24), Event::Key('i'), Event::Quit] {
+///     tx.send(event).unwrap();
+/// }
+///
+/// let mut typed = String::new();
+/// let exit_code = loop_matches!(rx.recv(), {
+///     Ok(Event::Key(c)) => typed.push(c),
+///     Ok(Event::Resize(w, h)) => println!("resized to {w}x{h}"),
+///     Ok(Event::Quit) => break 0,
+///     Err(_) => break 1,
+/// });
+///
+/// assert_eq!(typed, "hi");
+/// assert_eq!(exit_code, 0);
+/// ```
+#[macro_export]
+macro_rules! loop_matches {
+
+    ($e: expr, { $($a: tt)* }) => {
+        loop {
+            match $e {
+                $($a)*
+            }
+        }
+    };
+
+}
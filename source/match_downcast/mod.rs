@@ -0,0 +1,87 @@
+//! The [`crate::match_downcast!`] macro
+
+
+/// Evaluates the arm of the first type the value downcasts to
+///
+/// Tries to downcast the reference to the provided [`dyn Error`](std::error::Error)
+/// or [`dyn Any`](std::any::Any) value, boxed or borrowed, to the types of the arms in order.
+/// Evaluates the body of the first arm whose type the value downcasts to,
+/// with the optional name bound to the reference to the downcast value.
+/// Evaluates the body of the `else` arm if the value doesn't downcast to any of the types.
+///
+/// # Syntax
+///
+/// ```text
+/// match_downcast!(<expression>, [[<name>:] <type> => <body>,]* else => <body>)
+/// ```
+///
+/// - `<expression>` — value of [`dyn Error`](std::error::Error) or [`dyn Any`](std::any::Any)
+///   type, or a reference or a smart pointer to one.
+/// - `<name>` — optional name the reference to the downcast value is bound to within `<body>`.
+/// - `<type>` — concrete type to try to downcast the value to.
+/// - `<body>` — expression yielded by the macro if the arm is selected.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::match_downcast;
+/// # use std::{error::Error, io, num::ParseIntError};
+/// #
+/// let describe = |err: Box<dyn Error>| match_downcast!(err,
+///     err: io::Error => format!("io error of kind {:?}", err.kind()),
+///     ParseIntError => "parse error".to_string(),
+///     else => format!("unknown error: {err}"),
+/// );
+///
+/// let err = io::Error::from(io::ErrorKind::NotFound);
+/// assert_eq!(describe(err.into()), "io error of kind NotFound");
+///
+/// let err = "x".parse::<u8>().unwrap_err();
+/// assert_eq!(describe(err.into()), "parse error");
+///
+/// assert_eq!(describe("oops".into()), "unknown error: oops");
+/// ```
+///
+/// Downcasting [`dyn Any`](std::any::Any):
+/// ```
+/// # use el_macro::match_downcast;
+/// # use std::any::Any;
+/// #
+/// let payload: Box<dyn Any> = Box::new("boom");
+///
+/// let message = match_downcast!(payload,
+///     s: &str => s.to_string(),
+///     s: String => s.clone(),
+///     else => "non-string payload".to_string(),
+/// );
+/// assert_eq!(message, "boom");
+/// ```
+#[macro_export]
+macro_rules! match_downcast {
+
+    (@arms $s: ident [$($out: tt)*] else => $b: expr $(,)?) => {
+        $($out)* { $b }
+    };
+
+    (@arms $s: ident [$($out: tt)*] $n: ident: $t: ty => $b: expr, $($rest: tt)+) => {
+        $crate::match_downcast!(@arms $s [
+            $($out)*
+            if let Some($n) = $s.downcast_ref::<$t>() { $b } else
+        ] $($rest)+)
+    };
+
+    (@arms $s: ident [$($out: tt)*] $t: ty => $b: expr, $($rest: tt)+) => {
+        $crate::match_downcast!(@arms $s [
+            $($out)*
+            if $s.is::<$t>() { $b } else
+        ] $($rest)+)
+    };
+
+    ($e: expr, $($arms: tt)+) => {
+        {
+            let source = &$e;
+            $crate::match_downcast!(@arms source [] $($arms)+)
+        }
+    };
+
+}
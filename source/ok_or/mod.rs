@@ -0,0 +1,116 @@
+//! The [`crate::ok_or_continue!`] and [`crate::ok_or_break!`] macros
+
+
+/// Yields the unwrapped value or continues the enclosing loop
+///
+/// Shorthand for the most common use of the [`crate::bind!`] macro inside loops.
+/// [Tests](crate::bind::IntoResult) whether the value of the provided expression
+/// can be unwrapped and yields the unwrapped value if it can. Otherwise, calls the optional
+/// error handler and skips to the next iteration of the innermost loop.
+///
+/// # Syntax
+///
+/// ```text
+/// ok_or_continue!(<value-expr> [, <err-handler>])
+/// ```
+///
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+/// - `<err-handler>` — optional error handler that is called if there's no value to unwrap,
+///   with error object passed as the only argument.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::ok_or_continue;
+/// #
+/// let mut sum = 0;
+/// let mut errors = 0;
+///
+/// for line in ["1", "x", "41"] {
+///     let x = ok_or_continue!(line.parse::<i32>(), |_| errors += 1);
+///     sum += x;
+/// }
+///
+/// assert_eq!(sum, 42);
+/// assert_eq!(errors, 1);
+/// ```
+#[macro_export]
+macro_rules! ok_or_continue {
+
+    ($e: expr, $h: expr) => {
+        match {
+            use $crate::bind::IntoResult;
+            $e.into_result()
+        } {
+            Ok(value) => value,
+            Err(err) => {
+                $crate::private::call_with($h, err);
+                continue
+            },
+        }
+    };
+
+    ($e: expr) => {
+        $crate::ok_or_continue!($e, |_| { })
+    };
+
+}
+
+
+/// Yields the unwrapped value or breaks the enclosing loop
+///
+/// Shorthand for the most common use of the [`crate::bind!`] macro inside loops.
+/// [Tests](crate::bind::IntoResult) whether the value of the provided expression
+/// can be unwrapped and yields the unwrapped value if it can. Otherwise, calls the optional
+/// error handler and exits the innermost loop.
+///
+/// # Syntax
+///
+/// ```text
+/// ok_or_break!(<value-expr> [, <err-handler>])
+/// ```
+///
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+/// - `<err-handler>` — optional error handler that is called if there's no value to unwrap,
+///   with error object passed as the only argument.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::ok_or_break;
+/// #
+/// let mut incoming = vec![Ok(1), Ok(41), Err("disconnected"), Ok(100)].into_iter();
+/// let mut sum = 0;
+///
+/// loop {
+///     let x = ok_or_break!(incoming.next().unwrap(), |err| eprintln!("{err}"));
+///     sum += x;
+/// }
+///
+/// assert_eq!(sum, 42);
+/// ```
+#[macro_export]
+macro_rules! ok_or_break {
+
+    ($e: expr, $h: expr) => {
+        match {
+            use $crate::bind::IntoResult;
+            $e.into_result()
+        } {
+            Ok(value) => value,
+            Err(err) => {
+                $crate::private::call_with($h, err);
+                break
+            },
+        }
+    };
+
+    ($e: expr) => {
+        $crate::ok_or_break!($e, |_| { })
+    };
+
+}
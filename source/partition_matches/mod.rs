@@ -0,0 +1,69 @@
+//! The [`crate::partition_matches!`] macro
+
+
+/// Splits the items of an iterator into two collections by pattern
+///
+/// Matches each item of the provided [`IntoIterator`] against the pattern.
+/// Maps the bound variables of the items that match the pattern and the optional
+/// match guard into the first collection, and passes the rest of the items unchanged
+/// into the second one. Yields the tuple of the two collections.
+///
+/// The collections may be of any types implementing [`Default`] and [`Extend`],
+/// which, like for [`Iterator::partition`], usually have to be annotated.
+///
+/// # Syntax
+///
+/// ```text
+/// partition_matches!(<items-expr>, <pattern> [if <match-guard>] => <mapping>)
+/// ```
+///
+/// - `<items-expr>` — [`IntoIterator`] of items to be matched against `<pattern>`.
+/// - `<pattern>` — pattern that is matched against.
+/// - `<match-guard>` — optional match guard that further restricts the match.
+/// - `<mapping>` — expression the bound variables of a matching item are mapped
+///   into the item of the first collection with.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use el_macro::partition_matches;
+/// #
+/// let inputs = ["1", "x", "3"].map(str::parse::<i32>);
+///
+/// let (numbers, errors): (Vec<_>, Vec<_>) = partition_matches!(inputs, Ok(n) => n * 2);
+/// assert_eq!(numbers, [2, 6]);
+/// assert_eq!(errors.len(), 1);
+/// ```
+///
+/// Usage with match guard:
+/// ```
+/// # use el_macro::partition_matches;
+/// # use std::collections::BTreeSet;
+/// #
+/// let words = ["apple", "kiwi", "banana", "fig"];
+///
+/// let (long, short): (Vec<_>, BTreeSet<_>) = partition_matches!(
+///     words, w if w.len() > 4 => w.to_uppercase()
+/// );
+/// assert_eq!(long, ["APPLE", "BANANA"]);
+/// assert_eq!(short, BTreeSet::from(["fig", "kiwi"]));
+/// ```
+#[macro_export]
+macro_rules! partition_matches {
+
+    ($i: expr, $p: pat $(if $c: expr)? => $m: expr) => {
+        {
+            let mut matched = ::core::default::Default::default();
+            let mut rest = ::core::default::Default::default();
+            for item in $i {
+                match item {
+                    $p $(if $c)? => ::core::iter::Extend::extend(&mut matched, ::core::iter::once($m)),
+                    item => ::core::iter::Extend::extend(&mut rest, ::core::iter::once(item)),
+                }
+            }
+            (matched, rest)
+        }
+    };
+
+}
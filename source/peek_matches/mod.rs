@@ -0,0 +1,71 @@
+//! The [`crate::peek_matches!`] macro
+
+
+/// Consumes the next item of a [`Peekable`](std::iter::Peekable) iterator
+/// and maps its bound variables to [`Some`] if it matches the pattern.
+///
+/// Peeks at the next item of the provided [`Peekable`](std::iter::Peekable) iterator
+/// and, only if the item matches the pattern and the optional match guard evaluates to `true`,
+/// consumes the item and maps the pattern's bound variables into [`Some`].
+/// Returns [`None`] and leaves the item in the iterator otherwise.
+///
+/// Since the item is only peeked at while being tested, the variables bound by the pattern
+/// are references to the parts of the item within the match guard.
+///
+/// # Syntax
+///
+/// ```text
+/// peek_matches!(<iterator>, <pattern> [if <match-guard>] => <mapping>)
+/// ```
+///
+/// - `<iterator>` — place of type [`Peekable`](std::iter::Peekable), or a mutable reference to one.
+/// - `<pattern>` — pattern that the next item is matched against.
+/// - `<match-guard>` — optional match guard that further restricts the match.
+/// - `<mapping>` — expression the bound variables of the consumed item are mapped
+///   into [`Some`] value with.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use el_macro::peek_matches;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// enum Token { Number(i64), Comma }
+///
+/// let mut tokens = [Token::Number(1), Token::Comma, Token::Number(2)].into_iter().peekable();
+///
+/// assert_eq!(peek_matches!(tokens, Token::Comma => ()), None);
+/// assert_eq!(peek_matches!(tokens, Token::Number(n) => n), Some(1));
+/// assert_eq!(peek_matches!(tokens, Token::Comma => ()), Some(()));
+/// assert_eq!(tokens.next(), Some(Token::Number(2)));
+/// ```
+///
+/// Usage with match guard:
+/// ```
+/// # use el_macro::peek_matches;
+/// #
+/// let mut numbers = [-1, 2].into_iter().peekable();
+///
+/// let positive = |numbers: &mut std::iter::Peekable<_>| {
+///     peek_matches!(numbers, n if *n > 0 => n)
+/// };
+///
+/// assert_eq!(positive(&mut numbers), None);
+/// assert_eq!(numbers.next(), Some(-1));
+/// assert_eq!(positive(&mut numbers), Some(2));
+/// ```
+#[macro_export]
+macro_rules! peek_matches {
+
+    ($i: expr, $p: pat $(if $c: expr)? => $m: expr) => {
+        match $i.next_if(|item| match item {
+            $p $(if $c)? => true,
+            _ => false,
+        }) {
+            ::core::option::Option::Some($p) => ::core::option::Option::Some($m),
+            _ => ::core::option::Option::None,
+        }
+    };
+
+}
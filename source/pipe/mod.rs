@@ -0,0 +1,61 @@
+//! The [`crate::pipe!`] macro
+
+
+/// Threads the value through a sequence of functions
+///
+/// Evaluates the provided expression and passes its value to the first function,
+/// the result of the first function to the second one, and so on, left to right.
+/// Yields the result of the last function, or the value itself if no functions are given.
+///
+/// # Syntax
+///
+/// ```text
+/// pipe!(<expression> [=> <fn>]*)
+/// ```
+///
+/// - `<expression>` — expression whose value is passed to the first `<fn>`.
+/// - `<fn>` — function, closure or any other callable expression taking the single argument.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use el_macro::pipe;
+/// #
+/// fn parse(s: &str) -> i32 {
+///     s.trim().parse().unwrap_or_default()
+/// }
+///
+/// fn clamp(x: i32) -> i32 {
+///     x.clamp(0, 100)
+/// }
+///
+/// let x = pipe!(" 42 " => parse => clamp => |x| x * 2);
+/// assert_eq!(x, 84);
+///
+/// let x = pipe!(" 420 " => parse => clamp);
+/// assert_eq!(x, 100);
+/// ```
+///
+/// Combining with [`crate::bind!`]:
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::{bind, pipe};
+/// #
+/// bind!(x = pipe!("42" => str::parse::<i32> => Result::ok), or return);
+/// assert_eq!(x, 42);
+/// ```
+#[macro_export]
+macro_rules! pipe {
+
+    ($e: expr $(=> $f: expr)*) => {
+        {
+            let value = $e;
+            $(
+                let value = $crate::private::call_with($f, value);
+            )*
+            value
+        }
+    };
+
+}
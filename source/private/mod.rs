@@ -0,0 +1,156 @@
+//! Implementation details of the macros, not a part of the public API
+
+
+use std::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+
+/// Calls the closure.
+///
+/// Used instead of an immediately invoked closure expression, which clippy complains about.
+#[inline(always)]
+pub fn call<T>(f: impl FnOnce() -> T) -> T {
+    f()
+}
+
+
+/// Calls the error handler, discarding its result.
+///
+/// Used instead of a direct call, which clippy complains about if the handler is a closure.
+#[inline(always)]
+pub fn handle<E, R>(handler: impl FnOnce(E) -> R, err: E) {
+    handler(err);
+}
+
+
+/// Calls the function with the argument.
+///
+/// Used instead of a direct call, which clippy complains about if the function is a closure.
+#[inline(always)]
+pub fn call_with<T, R>(f: impl FnOnce(T) -> R, arg: T) -> R {
+    f(arg)
+}
+
+
+/// Gives the reference to the error of the value, if any, without consuming the value.
+///
+/// Used by the [`crate::on_err!`] macro, which yields the original value.
+pub trait PeekErr {
+
+    type Error;
+
+    fn peek_err(&self) -> Option<&Self::Error>;
+
+}
+
+impl<T> PeekErr for Option<T> {
+
+    type Error = ();
+
+    fn peek_err(&self) -> Option<&()> {
+        self.is_none().then_some(&())
+    }
+
+}
+
+impl<T, E> PeekErr for Result<T, E> {
+
+    type Error = E;
+
+    fn peek_err(&self) -> Option<&E> {
+        self.as_ref().err()
+    }
+
+}
+
+
+/// Takes the next item of an iterator if it satisfies the predicate.
+///
+/// Used by the macros that take items conditionally. The item that doesn't satisfy
+/// the predicate is consumed, unlike with [`Peekable::next_if`](std::iter::Peekable::next_if),
+/// which takes precedence over this method for [`Peekable`](std::iter::Peekable) iterators.
+pub trait NextIf: Iterator {
+
+    fn next_if(&mut self, predicate: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        self.next().filter(predicate)
+    }
+
+}
+
+impl<I: Iterator> NextIf for I { }
+
+
+/// Lock acquired by the [`crate::bind_locks!`] macro.
+///
+/// The locks are acquired in the order of their addresses, so that any two invocations
+/// acquiring the same locks do it in the same order and can't deadlock each other.
+pub trait OrderedLock {
+
+    type Guard;
+
+    fn address(&self) -> usize;
+
+    /// Blocks until the lock is acquired, or returns [`None`] if it's poisoned.
+    fn acquire(&self) -> Option<Self::Guard>;
+
+}
+
+impl<'a, T: ?Sized> OrderedLock for &'a Mutex<T> {
+
+    type Guard = MutexGuard<'a, T>;
+
+    fn address(&self) -> usize {
+        (*self as *const Mutex<T>).cast::<()>() as usize
+    }
+
+    fn acquire(&self) -> Option<Self::Guard> {
+        self.lock().ok()
+    }
+
+}
+
+/// [`RwLock`] acquired for reading by the [`crate::bind_locks!`] macro.
+pub struct ReadLock<'a, T: ?Sized>(pub &'a RwLock<T>);
+
+impl<'a, T: ?Sized> OrderedLock for ReadLock<'a, T> {
+
+    type Guard = RwLockReadGuard<'a, T>;
+
+    fn address(&self) -> usize {
+        (self.0 as *const RwLock<T>).cast::<()>() as usize
+    }
+
+    fn acquire(&self) -> Option<Self::Guard> {
+        self.0.read().ok()
+    }
+
+}
+
+/// [`RwLock`] acquired for writing by the [`crate::bind_locks!`] macro.
+pub struct WriteLock<'a, T: ?Sized>(pub &'a RwLock<T>);
+
+impl<'a, T: ?Sized> OrderedLock for WriteLock<'a, T> {
+
+    type Guard = RwLockWriteGuard<'a, T>;
+
+    fn address(&self) -> usize {
+        (self.0 as *const RwLock<T>).cast::<()>() as usize
+    }
+
+    fn acquire(&self) -> Option<Self::Guard> {
+        self.0.write().ok()
+    }
+
+}
+
+
+/// Counts the outcome of the [`crate::bind!`] call site if the `stats` feature is enabled.
+#[cfg(not(feature = "stats"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bind_stats {
+
+    ($n: ident, $r: expr) => {
+        $r
+    };
+
+}
@@ -0,0 +1,8 @@
+//! Procedural implementations of the macros
+//!
+//! Available with the `proc` feature. The procedural implementations behave the same
+//! as the declarative ones, but point the diagnostics at the respective parts
+//! of the invocation rather than at the macro definition.
+
+
+pub use el_macro_proc::{bind, if_matches};
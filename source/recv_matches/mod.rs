@@ -0,0 +1,76 @@
+//! The [`crate::recv_matches!`] macro
+
+
+/// Receives from a channel until an item matches the pattern and yields its mapped bound variables
+///
+/// Keeps receiving items from the provided channel receiver, skipping the items that don't match
+/// the pattern and the optional match guard, and yields the pattern's bound variables
+/// of the first matching item, mapped by the provided expression. If the channel gets
+/// disconnected before a matching item is received, calls the optional error handler
+/// with the receive error and evaluates the execution flow control expression.
+///
+/// Works with any receiver whose `recv` method returns an [unwrappable](crate::bind::IntoResult)
+/// value, such as [`std::sync::mpsc::Receiver`] or the `crossbeam-channel` one.
+///
+/// # Syntax
+///
+/// ```text
+/// recv_matches!(<receiver>, <pattern> [if <match-guard>] => <mapping>, or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `<receiver>` — channel receiver with a blocking `recv` method.
+/// - `<pattern>` — pattern that the received items are matched against.
+/// - `<match-guard>` — optional match guard that further restricts the match.
+/// - `<mapping>` — expression the bound variables of the matching item are mapped with.
+/// - `<err-handler>`, `<flow-ctl>` — same as for the [`crate::bind!`] macro, evaluated
+///   if the channel gets disconnected.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::recv_matches;
+/// # use std::sync::mpsc;
+/// #
+/// enum Message { Tick, Reply(u32), Stop }
+///
+/// let (tx, rx) = mpsc::channel();
+/// for message in [Message::Tick, Message::Reply(7), Message::Reply(42), Message::Stop] {
+///     tx.send(message).unwrap();
+/// }
+/// drop(tx);
+///
+/// let reply = recv_matches!(rx, Message::Reply(n) if n > 10 => n, or return);
+/// assert_eq!(reply, 42);
+///
+/// // prints 'receiving on a closed channel' and returns
+/// recv_matches!(rx, Message::Tick => (), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! recv_matches {
+
+    ($r: expr, $p: pat $(if $c: expr)? => $m: expr, or $h: expr, $f: expr) => {
+        match loop {
+            match {
+                use $crate::bind::IntoResult;
+                $r.recv().into_result()
+            } {
+                Ok($p) $(if $c)? => break Ok($m),
+                Ok(_) => { },
+                Err(err) => break Err(err),
+            }
+        } {
+            Ok(value) => value,
+            Err(err) => {
+                $crate::private::call_with($h, err);
+                $f
+            },
+        }
+    };
+
+    ($r: expr, $p: pat $(if $c: expr)? => $m: expr, or $f: expr) => {
+        $crate::recv_matches!($r, $p $(if $c)? => $m, or |_| { }, $f)
+    };
+
+}
@@ -0,0 +1,108 @@
+//! The [`crate::require_fields!`] macro
+
+
+/// Binds the unwrapped values of all the fields or reports every missing field
+///
+/// Meant for the `build()` methods of builders. Evaluates all the provided field expressions
+/// and [tests](crate::bind::IntoResult) whether the value of each can be unwrapped.
+/// Binds a variable named after each field to its unwrapped value if all of them
+/// can be unwrapped. Otherwise, passes the names of all the fields that can't be unwrapped,
+/// in order, to the optional error handler and evaluates the execution flow control expression.
+///
+/// Unlike the [`crate::bind_struct!`] macro, doesn't stop at the first missing field.
+///
+/// # Syntax
+///
+/// ```text
+/// require_fields!({ <field> [: <value-expr>] [, <field> [: <value-expr>]]* }, or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `<field>` — name of the field and of the newly created variable. If `<value-expr>`
+///   is not specified, the existing value of the variable with the same name is used.
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+/// - `<err-handler>` — optional error handler that is called if there are missing fields,
+///   with the [`MissingFields`](crate::error::MissingFields) passed as the only argument.
+/// - `<flow-ctl>` — expression used to control the execution flow in a case
+///   when there are missing fields.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::{require_fields, error::MissingFields};
+/// #
+/// #[derive(Default)]
+/// struct ConfigBuilder {
+///     host: Option<String>,
+///     port: Option<u16>,
+///     tls_cert: Option<String>,
+/// }
+///
+/// #[derive(Debug)]
+/// struct Config {
+///     host: String,
+///     port: u16,
+///     tls_cert: String,
+/// }
+///
+/// impl ConfigBuilder {
+///
+///     fn build(self) -> Result<Config, MissingFields> {
+///         let mut missing = MissingFields::new();
+///         require_fields!({ host: self.host, port: self.port, tls_cert: self.tls_cert },
+///             or |names| missing = names, return Err(missing));
+///         Ok(Config { host, port, tls_cert })
+///     }
+///
+/// }
+///
+/// let builder = ConfigBuilder { port: Some(443), ..Default::default() };
+/// assert_eq!(builder.build().unwrap_err().to_string(), "missing: host, tls_cert");
+///
+/// let builder = ConfigBuilder {
+///     host: Some("localhost".into()),
+///     port: Some(443),
+///     tls_cert: Some("cert.pem".into()),
+/// };
+/// assert_eq!(builder.build().unwrap().port, 443);
+/// ```
+#[macro_export]
+macro_rules! require_fields {
+
+    (@value $f: ident) => {
+        $f
+    };
+
+    (@value $f: ident $e: expr) => {
+        $e
+    };
+
+    ({ $($f: ident $(: $e: expr)?),+ $(,)? }, or $h: expr, $c: expr) => {
+        let ($($f,)+) = {
+            use $crate::bind::IntoResult;
+            let mut missing = $crate::error::MissingFields::new();
+            let values = ($(
+                match $crate::require_fields!(@value $f $($e)?).into_result() {
+                    ::core::result::Result::Ok(value) => ::core::option::Option::Some(value),
+                    ::core::result::Result::Err(_) => {
+                        missing.push(::core::stringify!($f));
+                        ::core::option::Option::None
+                    },
+                },
+            )+);
+            match values {
+                ($(::core::option::Option::Some($f),)+) => ($($f,)+),
+                _ => {
+                    $crate::private::call_with($h, missing);
+                    $c
+                },
+            }
+        };
+    };
+
+    ({ $($f: ident $(: $e: expr)?),+ $(,)? }, or $c: expr) => {
+        $crate::require_fields!({ $($f $(: $e)?),+ }, or |_| { }, $c);
+    };
+
+}
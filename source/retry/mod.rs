@@ -0,0 +1,247 @@
+//! The [`crate::retry!`] macro and related backoff [strategies](strategy)
+
+
+pub mod strategy;
+
+#[cfg(test)]
+mod test;
+
+
+use std::time::{Duration, Instant};
+
+use strategy::Backoff;
+
+
+/// Source of the current time and the waits for the time-bounded retries.
+///
+/// Used by [`retry_for`], so the tests can substitute the time instead of waiting,
+/// and the time can be measured without [`std::time`] where it isn't available.
+pub trait Clock {
+
+    /// Point in time measured by the clock.
+    type Instant: Copy;
+
+    /// Returns the current time.
+    fn now(&self) -> Self::Instant;
+
+    /// Returns the time elapsed since the earlier point, or zero if it is in the future.
+    fn elapsed(&self, since: Self::Instant) -> Duration;
+
+    /// Waits for the duration.
+    fn sleep(&self, duration: Duration);
+
+}
+
+/// [`Clock`] of the system, measuring the time with [`Instant`] and waiting
+/// with [`std::thread::sleep`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+
+    type Instant = Instant;
+
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn elapsed(&self, since: Instant) -> Duration {
+        Instant::now().saturating_duration_since(since)
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+
+}
+
+
+/// Runs the fallible operation until it succeeds or runs out of attempts.
+///
+/// Function form of the [`crate::retry!`] macro. At least one attempt is always made.
+/// Waits for the delay given by `backoff` between the attempts and returns
+/// the first success or the last error.
+pub fn retry<T, E>(
+    attempts: u32,
+    mut backoff: impl Backoff,
+    mut op: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt >= attempts => return Err(err),
+            Err(_) => {
+                let delay = backoff.delay(attempt);
+                if !delay.is_zero() {
+                    std::thread::sleep(delay);
+                }
+                attempt += 1;
+            },
+        }
+    }
+}
+
+
+/// Runs the fallible operation until it succeeds or runs out of time.
+///
+/// Time-bounded function form of the [`crate::retry!`] macro. At least one attempt is
+/// always made. Waits for the delay given by `backoff` between the attempts, as measured
+/// by `clock`, and returns the first success or the last error. Gives up without waiting
+/// if the delay would end after `timeout` has elapsed since the first attempt.
+pub fn retry_for<T, E>(
+    timeout: Duration,
+    mut backoff: impl Backoff,
+    clock: &impl Clock,
+    mut op: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let start = clock.now();
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                let delay = backoff.delay(attempt);
+                let elapsed = clock.elapsed(start);
+                if elapsed.saturating_add(delay) >= timeout {
+                    return Err(err);
+                }
+                if !delay.is_zero() {
+                    clock.sleep(delay);
+                }
+                attempt = attempt.saturating_add(1);
+            },
+        }
+    }
+}
+
+
+/// Evaluates the fallible expression until it succeeds or runs out of attempts
+///
+/// Re-evaluates the provided expression while its value [can't be unwrapped](crate::bind::IntoResult)
+/// and there are attempts or time left, waiting between the attempts as the backoff strategy says.
+/// Yields a [`Result`] holding the first unwrapped value or the last error, so the outcome
+/// can be bound with the [`crate::bind!`] macro or propagated with `?`.
+///
+/// The expression is evaluated inside a closure, so `return`, `break` and `continue`
+/// inside of it do not affect the enclosing function or loop.
+///
+/// # Syntax
+///
+/// ```text
+/// retry!(<attempts>, [backoff = <strategy>,] <fallible-expr>)
+/// retry!(for <timeout>, [backoff = <strategy>,] [clock = <clock>,] <fallible-expr>)
+/// ```
+///
+/// - `<attempts>` — maximum number of evaluations of `<fallible-expr>`, at least one is made.
+/// - `<timeout>` — [`Duration`] bounding the total time of the retries instead, as measured
+///   by `<clock>`. At least one attempt is made, and no attempt is made after
+///   the timeout. See [`retry_for`] for the details.
+/// - `<strategy>` — optional [`Backoff`] implementation defining the delays between the attempts.
+///   The [constructor functions](strategy#functions) from the [`strategy`] module are in scope,
+///   so `fixed(…)`, `exponential(…)` and `jitter(…)` can be used unqualified.
+///   Retries immediately if not specified.
+/// - `<clock>` — optional [`Clock`] measuring the time and waiting between the attempts
+///   of the time-bounded retries. [`SystemClock`] if not specified.
+/// - `<fallible-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+///
+/// # Examples
+///
+/// Basic usage:
+#[cfg_attr(all(feature = "impl-option", feature = "impl-result"), doc = "```")]
+#[cfg_attr(not(all(feature = "impl-option", feature = "impl-result")), doc = "```ignore")]
+/// # use el_macro::{bind, retry};
+/// # use std::time::Duration;
+/// #
+/// let mut calls = 0;
+/// let mut flaky = || {
+///     calls += 1;
+///     if calls < 3 { Err(calls) } else { Ok(42) }
+/// };
+///
+/// let x = retry!(3, backoff = exponential(Duration::from_millis(1)), { flaky() });
+/// assert_eq!(x, Ok(42));
+///
+/// bind!(x = retry!(2, { None::<i32> }), or return);
+/// unreachable!();
+/// ```
+///
+/// Getting the last error:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::retry;
+/// # use std::time::Duration;
+/// #
+/// let mut calls = 0;
+/// let x = retry!(4, backoff = jitter(fixed(Duration::from_millis(1))), {
+///     calls += 1;
+///     Err::<(), _>(calls)
+/// });
+/// assert_eq!(x, Err(4));
+/// ```
+///
+/// Bounding the total time:
+#[cfg_attr(feature = "impl-option", doc = "```")]
+#[cfg_attr(not(feature = "impl-option"), doc = "```ignore")]
+/// # use el_macro::retry;
+/// # use std::time::{Duration, Instant};
+/// #
+/// let start = Instant::now();
+/// let x = retry!(for Duration::from_millis(50), backoff = fixed(Duration::from_millis(10)), {
+///     None::<i32>
+/// });
+/// assert!(x.is_err());
+/// assert!(start.elapsed() < Duration::from_secs(1));
+/// ```
+#[macro_export]
+macro_rules! retry {
+
+    (for $t: expr, backoff = $b: expr, clock = $c: expr, $e: expr) => {
+        $crate::retry::retry_for(
+            $t,
+            {
+                #[allow(unused_imports)]
+                use $crate::retry::strategy::*;
+                $b
+            },
+            &$c,
+            || {
+                use $crate::bind::IntoResult;
+                $e.into_result()
+            },
+        )
+    };
+
+    (for $t: expr, backoff = $b: expr, $e: expr) => {
+        $crate::retry!(for $t, backoff = $b, clock = $crate::retry::SystemClock, $e)
+    };
+
+    (for $t: expr, clock = $c: expr, $e: expr) => {
+        $crate::retry!(for $t, backoff = $crate::retry::strategy::immediate(), clock = $c, $e)
+    };
+
+    (for $t: expr, $e: expr) => {
+        $crate::retry!(for $t, backoff = $crate::retry::strategy::immediate(), $e)
+    };
+
+    ($n: expr, backoff = $b: expr, $e: expr) => {
+        $crate::retry::retry(
+            $n,
+            {
+                #[allow(unused_imports)]
+                use $crate::retry::strategy::*;
+                $b
+            },
+            || {
+                use $crate::bind::IntoResult;
+                $e.into_result()
+            },
+        )
+    };
+
+    ($n: expr, $e: expr) => {
+        $crate::retry!($n, backoff = $crate::retry::strategy::immediate(), $e)
+    };
+
+}
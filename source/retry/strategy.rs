@@ -0,0 +1,113 @@
+//! Backoff strategies for the [`crate::retry!`] macro
+
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+
+/// Determines how long the [`crate::retry!`] macro waits between attempts.
+pub trait Backoff {
+
+    /// Returns the delay before the next attempt, given the number of the failed attempt
+    /// starting from 1.
+    fn delay(&mut self, attempt: u32) -> Duration;
+
+}
+
+
+/// Retries immediately.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Immediate;
+
+impl Backoff for Immediate {
+
+    fn delay(&mut self, _attempt: u32) -> Duration {
+        Duration::ZERO
+    }
+
+}
+
+
+/// Waits the same amount of time before every attempt.
+#[derive(Clone, Copy, Debug)]
+pub struct Fixed(pub Duration);
+
+impl Backoff for Fixed {
+
+    fn delay(&mut self, _attempt: u32) -> Duration {
+        self.0
+    }
+
+}
+
+
+/// Doubles the delay after every failed attempt, optionally capping it.
+#[derive(Clone, Copy, Debug)]
+pub struct Exponential {
+    base: Duration,
+    max: Option<Duration>,
+}
+
+impl Exponential {
+
+    /// Caps the delay at `max`.
+    pub fn with_max(self, max: Duration) -> Self {
+        Self { max: Some(max), ..self }
+    }
+
+}
+
+impl Backoff for Exponential {
+
+    fn delay(&mut self, attempt: u32) -> Duration {
+        let delay = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .map_or(Duration::MAX, |factor| self.base.saturating_mul(factor));
+        self.max.map_or(delay, |max| delay.min(max))
+    }
+
+}
+
+
+/// Randomizes the delay of the wrapped strategy to somewhere between zero and its value.
+#[derive(Clone, Debug)]
+pub struct Jitter<B> {
+    inner: B,
+    seed: RandomState,
+}
+
+impl<B: Backoff> Backoff for Jitter<B> {
+
+    fn delay(&mut self, attempt: u32) -> Duration {
+        let delay = self.inner.delay(attempt);
+        let mut hasher = self.seed.build_hasher();
+        hasher.write_u32(attempt);
+        let ratio = (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64;
+        delay.mul_f64(ratio)
+    }
+
+}
+
+
+/// Creates the [`Immediate`] strategy.
+pub fn immediate() -> Immediate {
+    Immediate
+}
+
+/// Creates the [`Fixed`] strategy.
+pub fn fixed(delay: Duration) -> Fixed {
+    Fixed(delay)
+}
+
+/// Creates the [`Exponential`] strategy starting from the `base` delay.
+pub fn exponential(base: Duration) -> Exponential {
+    Exponential { base, max: None }
+}
+
+/// Creates the [`Jitter`] strategy randomizing the delays of `inner`.
+pub fn jitter<B: Backoff>(inner: B) -> Jitter<B> {
+    Jitter { inner, seed: RandomState::new() }
+}
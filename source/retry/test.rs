@@ -0,0 +1,115 @@
+use std::{cell::Cell, time::Duration};
+
+use super::{retry_for, Clock, strategy::{Backoff, exponential, fixed, jitter}};
+
+
+#[test]
+fn exponential_doubles_and_caps() {
+
+    let mut backoff = exponential(Duration::from_millis(10));
+    assert_eq!(backoff.delay(1), Duration::from_millis(10));
+    assert_eq!(backoff.delay(2), Duration::from_millis(20));
+    assert_eq!(backoff.delay(4), Duration::from_millis(80));
+    assert_eq!(backoff.delay(100), Duration::MAX);
+
+    let mut backoff = exponential(Duration::from_millis(10)).with_max(Duration::from_millis(30));
+    assert_eq!(backoff.delay(2), Duration::from_millis(20));
+    assert_eq!(backoff.delay(3), Duration::from_millis(30));
+
+}
+
+
+#[test]
+fn jitter_stays_within_bounds() {
+
+    let mut backoff = jitter(fixed(Duration::from_millis(10)));
+    for attempt in 1..100 {
+        assert!(backoff.delay(attempt) <= Duration::from_millis(10));
+    }
+
+}
+
+
+#[test]
+#[cfg(feature = "impl-option")]
+fn zero_attempts_still_runs_once() {
+
+    let mut calls = 0;
+    let result = crate::retry!(0, {
+        calls += 1;
+        None::<()>
+    });
+    assert_eq!(result, Err(()));
+    assert_eq!(calls, 1);
+
+}
+
+
+struct ManualClock(Cell<Duration>);
+
+impl Clock for ManualClock {
+
+    type Instant = Duration;
+
+    fn now(&self) -> Duration {
+        self.0.get()
+    }
+
+    fn elapsed(&self, since: Duration) -> Duration {
+        self.0.get().saturating_sub(since)
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.0.set(self.0.get() + duration);
+    }
+
+}
+
+
+#[test]
+fn retry_for_stops_before_timeout() {
+
+    let clock = ManualClock(Cell::new(Duration::ZERO));
+    let start = clock.now();
+
+    let mut calls = 0;
+    let result = retry_for(Duration::from_secs(5), fixed(Duration::from_secs(1)), &clock, || {
+        calls += 1;
+        Err::<(), _>(calls)
+    });
+    assert_eq!(result, Err(5));
+    assert_eq!(clock.now() - start, Duration::from_secs(4));
+
+    let mut calls = 0;
+    let result = retry_for(Duration::from_secs(5), fixed(Duration::from_secs(1)), &clock, || {
+        calls += 1;
+        if calls < 3 { Err(calls) } else { Ok(42) }
+    });
+    assert_eq!(result, Ok(42));
+
+}
+
+
+#[test]
+#[cfg(feature = "impl-result")]
+fn retry_macro_uses_clock() {
+
+    let clock = ManualClock(Cell::new(Duration::ZERO));
+
+    let mut calls = 0;
+    let result = crate::retry!(for Duration::from_secs(3), backoff = fixed(Duration::from_secs(1)), clock = clock, {
+        calls += 1;
+        Err::<(), _>(calls)
+    });
+    assert_eq!(result, Err(3));
+    assert_eq!(clock.now(), Duration::from_secs(2));
+
+    let mut calls = 0;
+    let result = crate::retry!(for Duration::from_secs(3), clock = clock, {
+        calls += 1;
+        if calls < 10 { Err(calls) } else { Ok(42) }
+    });
+    assert_eq!(result, Ok(42));
+    assert_eq!(clock.now(), Duration::from_secs(2));
+
+}
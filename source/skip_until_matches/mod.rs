@@ -0,0 +1,83 @@
+//! The [`crate::skip_until_matches!`] macro
+
+
+/// Skips the items of an iterator until one matches the pattern
+///
+/// Advances the provided iterator past the items that don't match the pattern
+/// and the optional match guard, so that iterating can continue from the first item
+/// that matches.
+///
+/// With the mapping expression, consumes the matching item too and yields [`Some`] with
+/// its bound variables mapped by the expression, or [`None`] if the iterator runs out first.
+///
+/// Without the mapping expression, yields the number of the skipped items. If the iterator is
+/// [`Peekable`](std::iter::Peekable), the matching item is left in it. Otherwise, the matching
+/// item is consumed as well. Within the match guard of this form, the variables bound
+/// by the pattern are references to the parts of the item.
+///
+/// # Syntax
+///
+/// ```text
+/// skip_until_matches!(<iterator>, <pattern> [if <match-guard>] [=> <mapping>])
+/// ```
+///
+/// - `<iterator>` — place of the iterator, or a mutable reference to one.
+///   Evaluated once per item.
+/// - `<pattern>` — pattern that the items are matched against.
+/// - `<match-guard>` — optional match guard that further restricts the match.
+/// - `<mapping>` — optional expression the bound variables of the matching item are mapped with.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use el_macro::skip_until_matches;
+/// #
+/// let mut lines = ["noise", "---", "key: value", "---", "body"].into_iter();
+///
+/// let start = skip_until_matches!(lines, "---" => ());
+/// assert_eq!(start, Some(()));
+/// assert_eq!(lines.next(), Some("key: value"));
+///
+/// let missing = skip_until_matches!(lines, "+++" => ());
+/// assert_eq!(missing, None);
+/// ```
+///
+/// Segmenting with [`crate::take_while_matches!`]:
+/// ```
+/// # use el_macro::{skip_until_matches, take_while_matches};
+/// #
+/// let mut chars = "  width=42px".chars().peekable();
+///
+/// let skipped = skip_until_matches!(chars, c if c.is_ascii_digit());
+/// assert_eq!(skipped, 8);
+///
+/// let number: String = take_while_matches!(chars.by_ref(), c @ '0'..='9' => c).collect();
+/// assert_eq!(number, "42");
+/// ```
+#[macro_export]
+macro_rules! skip_until_matches {
+
+    ($i: expr, $p: pat $(if $c: expr)? => $m: expr) => {
+        loop {
+            match $i.next() {
+                ::core::option::Option::Some($p) $(if $c)? => break ::core::option::Option::Some($m),
+                ::core::option::Option::Some(_) => { },
+                ::core::option::Option::None => break ::core::option::Option::None,
+            }
+        }
+    };
+
+    ($i: expr, $p: pat $(if $c: expr)?) => {
+        {
+            #[allow(unused_imports)]
+            use $crate::private::NextIf as _;
+            let mut skipped = 0_usize;
+            while $i.next_if(|item| !::core::matches!(item, $p $(if $c)?)).is_some() {
+                skipped += 1;
+            }
+            skipped
+        }
+    };
+
+}
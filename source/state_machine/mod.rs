@@ -0,0 +1,101 @@
+//! The [`crate::state_machine!`] macro
+
+
+/// Declares a finite state machine with pattern-matched transitions
+///
+/// Generates the enum of the provided states, deriving [`Clone`], [`Copy`], [`Debug`],
+/// [`PartialEq`], [`Eq`] and [`Hash`], and its `step` method performing the transitions.
+///
+/// The `step(&mut self, event)` method matches the event against the transitions
+/// of the current state in order. Moves the machine into the target state of the first transition
+/// whose pattern and optional match guard match the event, and returns [`Ok`] with the new state.
+/// If there's no such transition, the state is left unchanged and [`Err`] with
+/// [`InvalidTransition`](crate::error::InvalidTransition) holding the state and the event
+/// is returned, which can be handled with the [`crate::bind!`] macro like any other error.
+///
+/// # Syntax
+///
+/// ```text
+/// state_machine! {
+///     [<attributes>] [<visibility>] enum <name>: <event-type> {
+///         [state <state> { [<pattern> [if <match-guard>] => <target-state>],* }]+
+///     }
+/// }
+/// ```
+///
+/// - `<attributes>`, `<visibility>`, `<name>` — attributes, visibility and name of the enum.
+/// - `<event-type>` — type of the events the machine reacts to.
+/// - `<state>` — name of the state, which becomes a variant of the enum.
+/// - `<pattern>`, `<match-guard>` — pattern and optional match guard that the event
+///   must match for the transition to happen.
+/// - `<target-state>` — name of the state the transition leads to.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::{bind, state_machine};
+/// #
+/// #[derive(Debug)]
+/// enum Event { Open, Close, Lock, Unlock(u32) }
+///
+/// state_machine! {
+///     /// State of a door.
+///     enum Door: Event {
+///         state Closed { Event::Open => Opened, Event::Lock => Locked }
+///         state Opened { Event::Close => Closed }
+///         state Locked { Event::Unlock(code) if code == 42 => Closed }
+///     }
+/// }
+///
+/// let mut door = Door::Closed;
+/// assert_eq!(door.step(Event::Lock).ok(), Some(Door::Locked));
+///
+/// let err = door.step(Event::Unlock(7)).unwrap_err();
+/// assert_eq!(err.to_string(), "no transition from state Locked on event Unlock(7)");
+/// assert_eq!(door, Door::Locked);
+///
+/// bind!(state = door.step(Event::Unlock(42)), or return);
+/// assert_eq!(state, Door::Closed);
+///
+/// // prints 'no transition from state Closed on event Close' and returns
+/// bind!(state = door.step(Event::Close), or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! state_machine {
+
+    (
+        $(#[$m: meta])*
+        $v: vis enum $n: ident: $e: ty {
+            $(state $s: ident { $($p: pat $(if $c: expr)? => $t: ident),* $(,)? })+
+        }
+    ) => {
+        $(#[$m])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        $v enum $n {
+            $($s,)+
+        }
+
+        impl $n {
+
+            /// Performs the transition of the current state matching the event.
+            #[allow(unreachable_patterns)]
+            pub fn step(
+                &mut self,
+                event: $e,
+            ) -> ::core::result::Result<Self, $crate::error::InvalidTransition<Self, $e>> {
+                let next = match (*self, event) {
+                    $($(($n::$s, $p) $(if $c)? => $n::$t,)*)+
+                    (state, event) => {
+                        return ::core::result::Result::Err($crate::error::InvalidTransition { state, event });
+                    },
+                };
+                *self = next;
+                ::core::result::Result::Ok(next)
+            }
+
+        }
+    };
+
+}
@@ -0,0 +1,122 @@
+//! Runtime statistics of the [`crate::bind!`] call sites
+//!
+//! Each call site of the [`crate::bind!`] macro, and of the macros built upon it,
+//! is registered the first time it's evaluated and counts how many times there was
+//! a value to bind and how many times there wasn't. Helps to find out which early exits
+//! of a long-running program are taken most often, without logging at every call site.
+//!
+//! Available with the `stats` feature.
+
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex, Once, PoisonError,
+};
+
+
+static SITES: Mutex<Vec<&'static CallSite>> = Mutex::new(Vec::new());
+
+
+/// Statistics of a single call site of the [`crate::bind!`] macro.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SiteStats {
+    /// Name of the source file of the call site.
+    pub file: &'static str,
+    /// Line of the call site.
+    pub line: u32,
+    /// Name of the bound variable.
+    pub name: &'static str,
+    /// Number of the times there was a value to bind.
+    pub successes: usize,
+    /// Number of the times there was no value to bind.
+    pub failures: usize,
+}
+
+
+/// Takes the statistics of all the call sites evaluated so far, in the order
+/// of their first evaluation.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, stats};
+/// #
+/// for input in ["4", "x", "2"] {
+///     bind!(number = input.parse::<i32>(), or continue);
+/// }
+///
+/// let stats = stats::snapshot();
+/// let site = stats.iter().find(|site| site.name == "number").unwrap();
+/// assert_eq!((site.successes, site.failures), (2, 1));
+/// ```
+pub fn snapshot() -> Vec<SiteStats> {
+    SITES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .map(|site| site.stats())
+        .collect()
+}
+
+
+/// Call site of the [`crate::bind!`] macro, counting the outcomes of its evaluations.
+#[doc(hidden)]
+pub struct CallSite {
+    file: &'static str,
+    line: u32,
+    name: &'static str,
+    successes: AtomicUsize,
+    failures: AtomicUsize,
+    registered: Once,
+}
+
+impl CallSite {
+
+    pub const fn new(file: &'static str, line: u32, name: &'static str) -> Self {
+        Self {
+            file,
+            line,
+            name,
+            successes: AtomicUsize::new(0),
+            failures: AtomicUsize::new(0),
+            registered: Once::new(),
+        }
+    }
+
+    pub fn record<T, E>(&'static self, result: Result<T, E>) -> Result<T, E> {
+        self.registered.call_once(|| {
+            SITES.lock().unwrap_or_else(PoisonError::into_inner).push(self);
+        });
+        let counter = if result.is_ok() { &self.successes } else { &self.failures };
+        counter.fetch_add(1, Ordering::Relaxed);
+        result
+    }
+
+    fn stats(&self) -> SiteStats {
+        SiteStats {
+            file: self.file,
+            line: self.line,
+            name: self.name,
+            successes: self.successes.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+        }
+    }
+
+}
+
+
+/// Counts the outcome of the [`crate::bind!`] call site if the `stats` feature is enabled.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bind_stats {
+
+    ($n: ident, $r: expr) => {
+        {
+            static SITE: $crate::stats::CallSite = $crate::stats::CallSite::new(
+                ::core::file!(), ::core::line!(), ::core::stringify!($n),
+            );
+            SITE.record($r)
+        }
+    };
+
+}
@@ -0,0 +1,74 @@
+//! The [`crate::take_while_matches!`] macro
+
+
+/// Yields the mapped bound variables of the leading items of an iterator that match the pattern
+///
+/// Yields an iterator that takes the items of the provided iterator while they match the pattern
+/// and the optional match guard, mapping the pattern's bound variables of each with the provided
+/// expression. Stops at the first item that doesn't match. If the provided iterator is
+/// [`Peekable`](std::iter::Peekable), the item that doesn't match is left in it.
+/// Otherwise, the item is consumed.
+///
+/// The provided iterator is moved into the yielded one, so to keep using it afterwards,
+/// pass a mutable reference to it, such as the one returned by [`Iterator::by_ref`].
+/// Within the match guard, the variables bound by the pattern are references
+/// to the parts of the item.
+///
+/// # Syntax
+///
+/// ```text
+/// take_while_matches!(<iterator>, <pattern> [if <match-guard>] => <mapping>)
+/// ```
+///
+/// - `<iterator>` — iterator to take the items from.
+/// - `<pattern>` — pattern that the items are matched against.
+/// - `<match-guard>` — optional match guard that further restricts the match.
+/// - `<mapping>` — expression the bound variables of a matching item are mapped with.
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```
+/// # use el_macro::take_while_matches;
+/// #
+/// let mut chars = "42px".chars().peekable();
+///
+/// let digits: String = take_while_matches!(chars.by_ref(), c @ '0'..='9' => c).collect();
+/// assert_eq!(digits, "42");
+/// assert_eq!(chars.collect::<String>(), "px");
+/// ```
+///
+/// Usage with match guard:
+/// ```
+/// # use el_macro::take_while_matches;
+/// #
+/// let lines = ["# title", "# author", "", "body"];
+///
+/// let header: Vec<_> = take_while_matches!(
+///     lines.iter(), line if line.starts_with('#') => &line[2..]
+/// ).collect();
+/// assert_eq!(header, ["title", "author"]);
+/// ```
+#[macro_export]
+macro_rules! take_while_matches {
+
+    ($i: expr, $p: pat $(if $c: expr)? => $m: expr) => {
+        {
+            let mut iter = $i;
+            ::core::iter::Iterator::fuse(::core::iter::from_fn(move || {
+                #[allow(unused_imports)]
+                use $crate::private::NextIf as _;
+                match iter.next_if(|item| match item {
+                    // the bindings are only used by the match guard here
+                    #[allow(unused_variables)]
+                    $p $(if $c)? => true,
+                    _ => false,
+                }) {
+                    ::core::option::Option::Some($p) => ::core::option::Option::Some($m),
+                    _ => ::core::option::Option::None,
+                }
+            }))
+        }
+    };
+
+}
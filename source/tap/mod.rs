@@ -0,0 +1,255 @@
+//! The [`crate::tap!`], [`crate::inspect!`] and [`crate::on_err!`] macros
+
+
+/// Runs a side effect with the value and yields the value unchanged
+///
+/// Evaluates the provided expression, passes a reference to its value to the side effect
+/// and yields the value itself. Allows inserting logging and other observation
+/// into an expression without splitting it into several statements.
+///
+/// # Syntax
+///
+/// ```text
+/// tap!(<expression>, |<pattern>| <side-effect>)
+/// tap!(<expression>, |<name>: <type>| <side-effect>)
+/// tap!(<expression>, <side-effect-fn>)
+/// ```
+///
+/// - `<expression>` — expression whose value is yielded.
+/// - `<pattern>`, `<name>` — pattern or name the reference to the value is bound to
+///   within `<side-effect>`.
+/// - `<type>` — type of the reference to the value.
+/// - `<side-effect>` — expression evaluated before the value is yielded.
+///   Its result is discarded.
+/// - `<side-effect-fn>` — function or closure called with the reference to the value
+///   as the only argument.
+///
+/// # Examples
+///
+/// Basic usage:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::{bind, tap};
+/// #
+/// let mut seen = Vec::new();
+///
+/// let x = tap!(40 + 2, |x| seen.push(*x));
+/// assert_eq!(x, 42);
+/// assert_eq!(seen, [42]);
+///
+/// let x = tap!(41 + 1, |x: &i32| seen.push(*x));
+/// assert_eq!(seen, [42, 42]);
+///
+/// bind!(x = tap!("42".parse::<i32>(), |res| eprintln!("parsed: {res:?}")), or return);
+/// assert_eq!(x, 42);
+/// ```
+///
+/// Passing a function:
+/// ```
+/// # use el_macro::tap;
+/// #
+/// fn log(cfg: &Vec<&str>) {
+///     eprintln!("{cfg:?}");
+/// }
+///
+/// let cfg = tap!(vec!["verbose"], log);
+/// assert_eq!(cfg, ["verbose"]);
+/// ```
+#[macro_export]
+macro_rules! tap {
+
+    ($e: expr, |$n: ident: $t: ty| $s: expr) => {
+        $crate::tap!($e, |$n| {
+            let $n: $t = $n;
+            $s
+        })
+    };
+
+    ($e: expr, |$p: pat_param| $s: expr) => {
+        {
+            let value = $e;
+            {
+                let $p = &value;
+                $s;
+            }
+            value
+        }
+    };
+
+    ($e: expr, $s: expr) => {
+        {
+            let value = $e;
+            $crate::private::call_with($s, &value);
+            value
+        }
+    };
+
+}
+
+
+/// Runs a side effect with the unwrappable value and yields the outcome as [`Result`]
+///
+/// [Converts](crate::bind::IntoResult) the value of the provided expression into [`Result`].
+/// If it holds an unwrappable value, passes a reference to it to the side effect.
+/// Yields the [`Result`], so the outcome can still be bound with the [`crate::bind!`] macro.
+///
+/// # Syntax
+///
+/// ```text
+/// inspect!(<value-expr>, |<pattern>| <side-effect>)
+/// inspect!(<value-expr>, |<name>: <type>| <side-effect>)
+/// inspect!(<value-expr>, <side-effect-fn>)
+/// ```
+///
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+/// - `<pattern>`, `<name>` — pattern or name the reference to the unwrapped value is bound to
+///   within `<side-effect>`.
+/// - `<type>` — type of the reference to the unwrapped value.
+/// - `<side-effect>` — expression evaluated if there's a value to unwrap. Its result is discarded.
+/// - `<side-effect-fn>` — function or closure called with the reference to the unwrapped value
+///   as the only argument if there's a value to unwrap.
+///
+/// # Examples
+///
+#[cfg_attr(all(feature = "impl-option", feature = "impl-result"), doc = "```")]
+#[cfg_attr(not(all(feature = "impl-option", feature = "impl-result")), doc = "```ignore")]
+/// # use el_macro::{bind, inspect};
+/// #
+/// let mut seen = Vec::new();
+///
+/// bind!(x = inspect!(Some(42), |x| seen.push(*x)), or return);
+/// assert_eq!(x, 42);
+///
+/// let x = inspect!(None::<i32>, |x| seen.push(*x));
+/// assert_eq!(x, Err(()));
+/// assert_eq!(seen, [42]);
+/// ```
+#[macro_export]
+macro_rules! inspect {
+
+    ($e: expr, |$n: ident: $t: ty| $s: expr) => {
+        $crate::inspect!($e, |$n| {
+            let $n: $t = $n;
+            $s
+        })
+    };
+
+    ($e: expr, |$p: pat_param| $s: expr) => {
+        {
+            use $crate::bind::IntoResult;
+            let result = $e.into_result();
+            if let Ok(value) = &result {
+                let $p = value;
+                $s;
+            }
+            result
+        }
+    };
+
+    ($e: expr, $s: expr) => {
+        {
+            use $crate::bind::IntoResult;
+            let result = $e.into_result();
+            if let Ok(value) = &result {
+                $crate::private::call_with($s, value);
+            }
+            result
+        }
+    };
+
+}
+
+
+/// Runs a side effect with the error and yields the original value
+///
+/// Evaluates the provided [`Option`] or [`Result`] expression. If it holds an error,
+/// passes a reference to it to the side effect, or a reference to `()` for [`None`].
+/// Yields the original value either way, so the error can still be handled
+/// by the `?` operator or the [`crate::bind!`] macro. Decouples observing the failures
+/// from controlling the execution flow.
+///
+/// # Syntax
+///
+/// ```text
+/// on_err!(<value-expr>, |<pattern>| <side-effect>)
+/// on_err!(<value-expr>, |<name>: <type>| <side-effect>)
+/// on_err!(<value-expr>, <side-effect-fn>)
+/// ```
+///
+/// - `<value-expr>` — expression of an [`Option`] or a [`Result`].
+/// - `<pattern>`, `<name>` — pattern or name the reference to the error is bound to
+///   within `<side-effect>`.
+/// - `<type>` — type of the reference to the error.
+/// - `<side-effect>` — expression evaluated if there's no value. Its result is discarded.
+/// - `<side-effect-fn>` — function or closure called with the reference to the error
+///   as the only argument if there's no value.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::on_err;
+/// # use std::num::ParseIntError;
+/// #
+/// fn parse_all(a: &str, b: &str, failures: &mut u32) -> Result<(i32, i32), ParseIntError> {
+///     let a = on_err!(a.parse::<i32>(), |_| *failures += 1)?;
+///     let b = on_err!(b.parse::<i32>(), |_| *failures += 1)?;
+///     Ok((a, b))
+/// }
+///
+/// let mut failures = 0;
+/// assert!(parse_all("4", "2", &mut failures).is_ok());
+/// assert!(parse_all("4", "x", &mut failures).is_err());
+/// assert!(parse_all("x", "2", &mut failures).is_err());
+/// assert_eq!(failures, 2);
+/// ```
+///
+/// Observing the [`None`] values within a function returning [`Option`]:
+/// ```
+/// # use el_macro::on_err;
+/// #
+/// fn first_char_digit(s: &str, misses: &mut Vec<String>) -> Option<u32> {
+///     let c = on_err!(s.chars().next(), |_| misses.push(format!("{s:?} is empty")))?;
+///     on_err!(c.to_digit(10), |_| misses.push(format!("{c:?} isn't a digit")))
+/// }
+///
+/// let mut misses = Vec::new();
+/// assert_eq!(first_char_digit("42", &mut misses), Some(4));
+/// assert_eq!(first_char_digit("", &mut misses), None);
+/// assert_eq!(first_char_digit("x", &mut misses), None);
+/// assert_eq!(misses, [r#""" is empty"#, "'x' isn't a digit"]);
+/// ```
+#[macro_export]
+macro_rules! on_err {
+
+    ($e: expr, |$n: ident: $t: ty| $s: expr) => {
+        $crate::on_err!($e, |$n| {
+            let $n: $t = $n;
+            $s
+        })
+    };
+
+    ($e: expr, |$p: pat_param| $s: expr) => {
+        {
+            use $crate::private::PeekErr;
+            let value = $e;
+            if let Some(err) = value.peek_err() {
+                let $p = err;
+                $s;
+            }
+            value
+        }
+    };
+
+    ($e: expr, $s: expr) => {
+        {
+            use $crate::private::PeekErr;
+            let value = $e;
+            if let Some(err) = value.peek_err() {
+                $crate::private::call_with($s, err);
+            }
+            value
+        }
+    };
+
+}
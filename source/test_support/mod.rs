@@ -0,0 +1,175 @@
+//! Scripted fallible sources for testing the code that handles failures
+//!
+//! The sources implement [`IntoResult`] by shared reference, so the same source
+//! can be tested repeatedly, such as by the [`crate::retry!`] macro, and count
+//! the times they were tested.
+//!
+//! Available with the `test-util` feature.
+
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    convert::Infallible,
+};
+
+use crate::bind::IntoResult;
+
+
+/// Source failing the given number of times and succeeding afterwards.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{retry, test_support::FailsFirst};
+/// #
+/// let source = FailsFirst::new(2, 42, "busy");
+///
+/// assert_eq!(retry!(2, &source), Err("busy"));
+/// assert_eq!(retry!(2, &source), Ok(42));
+/// assert_eq!(source.calls(), 3);
+/// ```
+#[derive(Debug)]
+pub struct FailsFirst<T, E> {
+    failures: usize,
+    calls: Cell<usize>,
+    value: T,
+    error: E,
+}
+
+impl<T, E> FailsFirst<T, E> {
+
+    /// Creates the source yielding `error` the first `failures` times and `value` afterwards.
+    pub fn new(failures: usize, value: T, error: E) -> Self {
+        Self { failures, calls: Cell::new(0), value, error }
+    }
+
+    /// Number of times the source was tested.
+    pub fn calls(&self) -> usize {
+        self.calls.get()
+    }
+
+}
+
+impl<T: Clone, E: Clone> IntoResult for &FailsFirst<T, E> {
+
+    type Value = T;
+    type Error = E;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        let calls = self.calls.get();
+        self.calls.set(calls + 1);
+        match calls < self.failures {
+            true => Err(self.error.clone()),
+            false => Ok(self.value.clone()),
+        }
+    }
+
+}
+
+
+/// Source always failing with the given error.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{bind, test_support::AlwaysErr};
+/// #
+/// let source = AlwaysErr::new("offline");
+///
+/// let mut handled = 0;
+/// for _ in 0..3 {
+///     bind!(x = &source, or |err| { assert_eq!(err, "offline"); handled += 1 }, continue);
+/// }
+/// assert_eq!(handled, 3);
+/// assert_eq!(source.calls(), 3);
+/// ```
+#[derive(Debug)]
+pub struct AlwaysErr<E> {
+    calls: Cell<usize>,
+    error: E,
+}
+
+impl<E> AlwaysErr<E> {
+
+    /// Creates the source yielding `error` every time.
+    pub fn new(error: E) -> Self {
+        Self { calls: Cell::new(0), error }
+    }
+
+    /// Number of times the source was tested.
+    pub fn calls(&self) -> usize {
+        self.calls.get()
+    }
+
+}
+
+impl<E: Clone> IntoResult for &AlwaysErr<E> {
+
+    type Value = Infallible;
+    type Error = E;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.calls.set(self.calls.get() + 1);
+        Err(self.error.clone())
+    }
+
+}
+
+
+/// Source yielding the scripted outcomes in order.
+///
+/// Panics if tested more times than there are outcomes in the script.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{retry, test_support::Script};
+/// #
+/// let source = Script::new([Err("busy"), Err("timeout"), Ok(42)]);
+///
+/// assert_eq!(retry!(5, &source), Ok(42));
+/// assert_eq!(source.calls(), 3);
+/// assert_eq!(source.remaining(), 0);
+/// ```
+#[derive(Debug)]
+pub struct Script<T, E> {
+    calls: Cell<usize>,
+    outcomes: RefCell<VecDeque<Result<T, E>>>,
+}
+
+impl<T, E> Script<T, E> {
+
+    /// Creates the source yielding the provided outcomes in order.
+    pub fn new(outcomes: impl IntoIterator<Item = Result<T, E>>) -> Self {
+        Self { calls: Cell::new(0), outcomes: RefCell::new(outcomes.into_iter().collect()) }
+    }
+
+    /// Number of times the source was tested.
+    pub fn calls(&self) -> usize {
+        self.calls.get()
+    }
+
+    /// Number of the outcomes left in the script.
+    pub fn remaining(&self) -> usize {
+        self.outcomes.borrow().len()
+    }
+
+}
+
+impl<T, E> IntoResult for &Script<T, E> {
+
+    type Value = T;
+    type Error = E;
+
+    #[inline(always)]
+    fn into_result(self) -> Result<Self::Value, Self::Error> {
+        self.calls.set(self.calls.get() + 1);
+        self.outcomes.borrow_mut()
+            .pop_front()
+            .unwrap_or_else(|| panic!("the script is exhausted after {} outcomes", self.calls() - 1))
+    }
+
+}
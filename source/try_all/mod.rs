@@ -0,0 +1,183 @@
+//! The [`crate::try_all!`] and [`crate::try_join_all_errors!`] macros
+
+
+/// Evaluates all the fallible expressions and yields all the values or all the errors
+///
+/// Evaluates every provided expression, without stopping at failures, and
+/// [tests](crate::bind::IntoResult) whether its value can be unwrapped. Yields [`Ok`] with
+/// the tuple of all the unwrapped values if all of them can be unwrapped. Otherwise, yields [`Err`]
+/// with the [`Vec`] of [`IndexedError`](crate::error::IndexedError)s, one per failed expression,
+/// in the order of the expressions.
+///
+/// The values may be of different types, while the errors must be of the same type.
+///
+/// If the expressions are named, yields [`Err`] with the
+/// [`MissingFields`](crate::error::MissingFields) listing the names of the failed expressions
+/// instead. Then the errors may be of different types as well, as they are discarded.
+///
+/// # Syntax
+///
+/// ```text
+/// try_all!(<value-expr> [, <value-expr>]*)
+/// try_all!(<name>: <value-expr> [, <name>: <value-expr>]*)
+/// ```
+///
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+/// - `<name>` — name of the field or step the expression provides the value for.
+///
+/// # Examples
+///
+#[cfg_attr(all(feature = "impl-option", feature = "impl-result"), doc = "```")]
+#[cfg_attr(not(all(feature = "impl-option", feature = "impl-result")), doc = "```ignore")]
+/// # use el_macro::{bind, try_all, error::IndexedError};
+/// #
+/// let x = try_all!("42".parse::<u8>(), "-1".parse::<i32>());
+/// assert_eq!(x, Ok((42, -1)));
+///
+/// let x = try_all!("x".parse::<u8>(), "42".parse::<u8>(), "256".parse::<u8>());
+/// let errors = x.unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors[0].index, 0);
+/// assert_eq!(errors[1].index, 2);
+///
+/// bind!(x = try_all!(Some(42), None::<&str>), or |errors: Vec<IndexedError<()>>| {
+///     assert_eq!(errors.len(), 1);
+/// }, return);
+/// unreachable!();
+/// ```
+///
+/// Naming the expressions:
+#[cfg_attr(all(feature = "impl-option", feature = "impl-result"), doc = "```")]
+#[cfg_attr(not(all(feature = "impl-option", feature = "impl-result")), doc = "```ignore")]
+/// # use el_macro::try_all;
+/// #
+/// let x = try_all!(host: Some("localhost"), port: "x".parse::<u16>(), tls_cert: None::<&str>);
+/// assert_eq!(x.unwrap_err().to_string(), "missing: port, tls_cert");
+///
+/// let x = try_all!(host: Some("localhost"), port: "443".parse::<u16>());
+/// assert_eq!(x, Ok(("localhost", 443)));
+/// ```
+#[macro_export]
+macro_rules! try_all {
+
+    (@eval [$($r: ident)*] [$($s: tt)*] $e: expr $(, $rest: expr)*) => {
+        $crate::try_all!(@eval [$($r)* result] [$($s)* let result = $e.into_result();] $($rest),*)
+    };
+
+    (@eval [$($r: ident)*] [$($s: tt)*]) => {
+        {
+            use $crate::bind::IntoResult;
+            $($s)*
+            match ($($r,)*) {
+                ($(Ok($r),)*) => Ok(($($r,)*)),
+                ($($r,)*) => {
+                    let mut errors = ::std::vec::Vec::new();
+                    let mut index = 0;
+                    $(
+                        if let Err(error) = $r {
+                            errors.push($crate::error::IndexedError { index, error });
+                        }
+                        index += 1;
+                    )*
+                    let _ = index;
+                    Err(errors)
+                },
+            }
+        }
+    };
+
+    ($($n: ident: $e: expr),+ $(,)?) => {
+        {
+            use $crate::bind::IntoResult;
+            let mut missing = $crate::error::MissingFields::new();
+            let values = ($(
+                match $e.into_result() {
+                    ::core::result::Result::Ok(value) => ::core::option::Option::Some(value),
+                    ::core::result::Result::Err(_) => {
+                        missing.push(::core::stringify!($n));
+                        ::core::option::Option::None
+                    },
+                },
+            )+);
+            match values {
+                ($(::core::option::Option::Some($n),)+) => ::core::result::Result::Ok(($($n,)+)),
+                _ => ::core::result::Result::Err(missing),
+            }
+        }
+    };
+
+    ($($e: expr),+ $(,)?) => {
+        $crate::try_all!(@eval [] [] $($e),+)
+    };
+
+}
+
+
+/// Evaluates all the fallible expressions and yields all the values or all the errors
+///
+/// Evaluates every provided expression, without stopping at failures, and
+/// [tests](crate::bind::IntoResult) whether its value can be unwrapped. Yields [`Ok`] with
+/// the tuple of all the unwrapped values if all of them can be unwrapped. Otherwise, yields [`Err`]
+/// with the [`Vec`] of the errors of the failed expressions, in the order of the expressions.
+///
+/// A counterpart to the [`crate::try_zip!`] macro, which stops at the first error instead.
+/// Unlike the [`crate::try_all!`] macro, doesn't wrap the errors with their indices.
+///
+/// # Syntax
+///
+/// ```text
+/// try_join_all_errors!(<value-expr> [, <value-expr>]*)
+/// ```
+///
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::try_join_all_errors;
+/// #
+/// let x = try_join_all_errors!("42".parse::<u8>(), "-1".parse::<i32>());
+/// assert_eq!(x, Ok((42, -1)));
+///
+/// let mut evaluated = 0;
+/// let mut parse = |s: &str| { evaluated += 1; s.parse::<u8>() };
+/// let x = try_join_all_errors!(parse("x"), parse("42"), parse("256"));
+/// let errors: Vec<String> = x.unwrap_err().iter().map(ToString::to_string).collect();
+/// assert_eq!(errors, ["invalid digit found in string", "number too large to fit in target type"]);
+/// assert_eq!(evaluated, 3);
+/// ```
+#[macro_export]
+macro_rules! try_join_all_errors {
+
+    (@eval $errors: ident [$($v: ident)*] [$($s: tt)*] $e: expr $(, $rest: expr)*) => {
+        $crate::try_join_all_errors!(@eval $errors [$($v)* value] [$($s)*
+            let value = match $e.into_result() {
+                ::core::result::Result::Ok(value) => ::core::option::Option::Some(value),
+                ::core::result::Result::Err(err) => {
+                    $errors.push(err);
+                    ::core::option::Option::None
+                },
+            };
+        ] $($rest),*)
+    };
+
+    (@eval $errors: ident [$($v: ident)*] [$($s: tt)*]) => {
+        {
+            use $crate::bind::IntoResult;
+            let mut $errors = ::std::vec::Vec::new();
+            $($s)*
+            match ($($v,)*) {
+                ($(::core::option::Option::Some($v),)*) => ::core::result::Result::Ok(($($v,)*)),
+                _ => ::core::result::Result::Err($errors),
+            }
+        }
+    };
+
+    ($($e: expr),+ $(,)?) => {
+        $crate::try_join_all_errors!(@eval errors [] [] $($e),+)
+    };
+
+}
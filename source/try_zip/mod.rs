@@ -0,0 +1,62 @@
+//! The [`crate::try_zip!`] macro
+
+
+/// Evaluates the fallible expressions in order and yields all the values or the first error
+///
+/// Evaluates the provided expressions in order and [tests](crate::bind::IntoResult) whether
+/// the value of each can be unwrapped. Yields [`Ok`] with the tuple of all the unwrapped values
+/// if all of them can be unwrapped. Otherwise, yields [`Err`] with the error of the first
+/// expression that can't be unwrapped, without evaluating the rest of the expressions.
+///
+/// The values may be of different types, while the errors must be of the same type.
+/// For evaluating all the expressions and collecting all the errors,
+/// see the [`crate::try_join_all_errors!`] and [`crate::try_all!`] macros.
+///
+/// # Syntax
+///
+/// ```text
+/// try_zip!(<value-expr> [, <value-expr>]*)
+/// ```
+///
+/// - `<value-expr>` — expression [being tested](crate::bind::IntoResult) to contain
+///   an unwrappable value.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::{bind, try_zip};
+/// # use std::num::ParseIntError;
+/// #
+/// let x = try_zip!("42".parse::<u8>(), "-1".parse::<i32>());
+/// assert_eq!(x, Ok((42, -1)));
+///
+/// let mut evaluated = 0;
+/// let mut parse = |s: &str| { evaluated += 1; s.parse::<u8>() };
+/// let x = try_zip!(parse("1"), parse("x"), parse("2"));
+/// assert!(x.is_err());
+/// assert_eq!(evaluated, 2);
+///
+/// // prints 'invalid digit found in string' and returns
+/// bind!(pair = try_zip!("1".parse::<u8>(), "x".parse::<u8>()),
+///     or |err: ParseIntError| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! try_zip {
+
+    ($($e: expr),+ $(,)?) => {
+        {
+            use $crate::bind::IntoResult;
+            'zip: {
+                ::core::result::Result::Ok(($(
+                    match $e.into_result() {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(err) => break 'zip ::core::result::Result::Err(err),
+                    },
+                )+))
+            }
+        }
+    };
+
+}
@@ -0,0 +1,106 @@
+//! The [`crate::unwrap_chain!`] macro and related [`MissingLink`] error type
+
+
+pub use crate::error::MissingLink;
+
+
+/// Walks the chain of optional steps and yields or binds the final value
+///
+/// Evaluates the chain of field accesses and method calls where each `?` marks a step
+/// yielding [`Option`], as the `?` operator would in a function returning [`Option`].
+/// Unlike the `?` operator, reports the step of the chain that is [`None`]
+/// with the [`MissingLink`] error.
+///
+/// In the expression form, yields [`Ok`] with the final value of the chain
+/// or [`Err`] with the [`MissingLink`]. In the binding form, binds the final value
+/// the same way the [`crate::bind!`] macro does.
+///
+/// # Syntax
+///
+/// ```text
+/// unwrap_chain!(<chain>)
+/// unwrap_chain!([mut] <var-name> = <chain>, or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `<chain>` — expression where the `?` operator marks [`Option`] steps to unwrap.
+/// - `mut`, `<var-name>`, `<err-handler>`, `<flow-ctl>` — same as for
+///   the [`crate::bind!`] macro. `<err-handler>` receives the [`MissingLink`].
+///
+/// # Examples
+///
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::{unwrap_chain, error::MissingLink};
+/// #
+/// struct Config { server: Option<Server> }
+/// struct Server { tls: Option<Tls> }
+/// struct Tls { cert_path: String }
+///
+/// let cfg = Config {
+///     server: Some(Server { tls: Some(Tls { cert_path: "cert.pem".into() }) }),
+/// };
+///
+/// unwrap_chain!(path = cfg.server.as_ref()?.tls.as_ref()?.cert_path.as_str(), or return);
+/// assert_eq!(path, "cert.pem");
+///
+/// let cfg = Config { server: Some(Server { tls: None }) };
+///
+/// let x = unwrap_chain!(cfg.server.as_ref()?.tls.as_ref()?.cert_path.as_str());
+/// assert!(x.is_err_and(|err| err.step == 1));
+///
+/// // prints the error naming the `cfg.server?.tls` step and returns
+/// unwrap_chain!(path = cfg.server?.tls?.cert_path, or |err| eprintln!("{err}"), return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! unwrap_chain {
+
+    (@chain [$v: ident $l: lifetime] [$($step: tt)*] [$($path: tt)*] [$($stmts: tt)*]
+        [$($cur: tt)*] ? $($rest: tt)*) => {
+        $crate::unwrap_chain!(@chain [$v $l] [$($step)* + 1] [$($path)* ?] [
+            $($stmts)*
+            let $v = match ($($cur)*) {
+                Some(value) => value,
+                None => break $l Err($crate::error::MissingLink {
+                    step: 0 $($step)*,
+                    path: ::core::stringify!($($path)*),
+                }),
+            };
+        ] [$v] $($rest)*)
+    };
+
+    (@chain [$v: ident $l: lifetime] [$($step: tt)*] [$($path: tt)*] [$($stmts: tt)*]
+        [$($cur: tt)*] $t: tt $($rest: tt)*) => {
+        $crate::unwrap_chain!(@chain [$v $l] [$($step)*] [$($path)* $t] [$($stmts)*]
+            [$($cur)* $t] $($rest)*)
+    };
+
+    (@chain [$v: ident $l: lifetime] [$($step: tt)*] [$($path: tt)*] [$($stmts: tt)*]
+        [$($cur: tt)*]) => {
+        $l: {
+            $($stmts)*
+            Ok($($cur)*)
+        }
+    };
+
+    (@bind [$($head: tt)*] [$($chain: tt)*] , or $($clause: tt)*) => {
+        $crate::bind!($($head)* = $crate::unwrap_chain!($($chain)*), or $($clause)*);
+    };
+
+    (@bind [$($head: tt)*] [$($chain: tt)*] $t: tt $($rest: tt)*) => {
+        $crate::unwrap_chain!(@bind [$($head)*] [$($chain)* $t] $($rest)*);
+    };
+
+    (mut $n: ident = $($rest: tt)+) => {
+        $crate::unwrap_chain!(@bind [mut $n] [] $($rest)+);
+    };
+
+    ($n: ident = $($rest: tt)+) => {
+        $crate::unwrap_chain!(@bind [$n] [] $($rest)+);
+    };
+
+    ($($chain: tt)+) => {
+        $crate::unwrap_chain!(@chain [value 'chain] [] [] [] [] $($chain)+)
+    };
+
+}
@@ -0,0 +1,137 @@
+//! The [`crate::validate!`] macro, related [`Invalid`] error type and [`Predicate`] trait
+
+
+pub use crate::error::Invalid;
+
+
+/// Reusable check of a value, named for the error reports.
+///
+/// Checked by the [`Validated`](crate::adapters::Validated) adapter. Implemented
+/// for the functions and closures taking the reference to the value and returning `bool`,
+/// named after their type.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::validate::Predicate;
+/// #
+/// struct NonEmpty;
+///
+/// impl Predicate<str> for NonEmpty {
+///
+///     fn test(&self, value: &str) -> bool {
+///         !value.is_empty()
+///     }
+///
+///     fn name(&self) -> &'static str {
+///         "non-empty"
+///     }
+///
+/// }
+///
+/// assert!(NonEmpty.test("el-macro"));
+/// assert!(!NonEmpty.test(""));
+/// ```
+pub trait Predicate<T: ?Sized> {
+
+    /// Tells whether the value passes the check.
+    fn test(&self, value: &T) -> bool;
+
+    /// Name of the check, reported in the [`Invalid`] error.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+}
+
+impl<T: ?Sized, F: Fn(&T) -> bool> Predicate<T> for F {
+
+    fn test(&self, value: &T) -> bool {
+        self(value)
+    }
+
+}
+
+
+/// Yields the value if it passes the check or controls the execution flow
+///
+/// Evaluates the provided expression and checks whether its value is within the range
+/// or satisfies the predicate. Yields the value if it does. Otherwise, executes the error handler
+/// with the [`Invalid`] error holding the value and the stringified check,
+/// and evaluates the execution flow control expression.
+///
+/// # Syntax
+///
+/// ```text
+/// validate!(<expression>, <range>, or [<err-handler>,] <flow-ctl>)
+/// validate!(<expression>, |<pattern>| <predicate>, or [<err-handler>,] <flow-ctl>)
+/// ```
+///
+/// - `<expression>` — expression whose value is checked.
+/// - `<range>` — expression of a type implementing [`RangeBounds`](std::ops::RangeBounds)
+///   the value must be [contained](std::ops::RangeBounds::contains) in.
+/// - `<pattern>` — pattern the reference to the value is bound to within `<predicate>`.
+/// - `<predicate>` — boolean expression the value must satisfy.
+/// - `<err-handler>` — optional error handler that is called if the value fails the check,
+///   with the [`Invalid`] error passed as the only argument.
+/// - `<flow-ctl>` — expression used to control the execution flow in a case
+///   when the value fails the check.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::{validate, error::Invalid};
+/// #
+/// let percent = validate!(42, 0..=100, or return);
+/// assert_eq!(percent, 42);
+///
+/// let name = validate!("el-macro", |s| s.is_ascii(), or return);
+/// assert_eq!(name, "el-macro");
+///
+/// let report = |err: Invalid<i32>| assert_eq!(err.to_string(), "420 fails the check `0..=100`");
+///
+/// // calls `report` and returns
+/// validate!(420, 0..=100, or report, return);
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! validate {
+
+    ($v: expr, |$p: pat_param| $c: expr, or $h: expr, $f: expr) => {
+        {
+            let value = $v;
+            if {
+                let $p = &value;
+                $c
+            } {
+                value
+            } else {
+                let invalid = $crate::error::Invalid { value, check: ::core::stringify!(|$p| $c) };
+                $crate::private::call_with($h, invalid);
+                $f
+            }
+        }
+    };
+
+    ($v: expr, |$p: pat_param| $c: expr, or $f: expr) => {
+        $crate::validate!($v, |$p| $c, or |_| { }, $f)
+    };
+
+    ($v: expr, $r: expr, or $h: expr, $f: expr) => {
+        {
+            let value = $v;
+            if ::std::ops::RangeBounds::contains(&$r, &value) {
+                value
+            } else {
+                let invalid = $crate::error::Invalid { value, check: ::core::stringify!($r) };
+                $crate::private::call_with($h, invalid);
+                $f
+            }
+        }
+    };
+
+    ($v: expr, $r: expr, or $f: expr) => {
+        $crate::validate!($v, $r, or |_| { }, $f)
+    };
+
+}
@@ -0,0 +1,111 @@
+//! The [`crate::visit_matches!`] macro
+
+
+/// Searches a tree of nodes for the first one that matches the pattern
+///
+/// Visits the nodes of the tree in depth-first pre-order, starting with the provided root
+/// and descending into the children reached by the provided accessors of each node,
+/// in the order of the accessors. Maps the bound variables of the first node that matches
+/// the pattern and the optional match guard into [`Some`]. Returns [`None`]
+/// if none of the nodes match.
+///
+/// All the nodes are of the same type. Since the nodes are visited by reference, the variables
+/// bound by the pattern are references to the parts of the node.
+///
+/// # Syntax
+///
+/// ```text
+/// visit_matches!(<root>, [<accessor> [, <accessor>]*], <pattern> [if <match-guard>] => <mapping>)
+/// ```
+///
+/// - `<root>` — root node of the tree.
+/// - `<accessor>` — field path of the children of a node, starting with `.`, such as `.lhs`
+///   or `.body.stmts`, one of:
+///   - `<path>` — a single child, either a node or a smart pointer to one, such as [`Box`];
+///   - `<path>?` — an optional child in an [`Option`];
+///   - `<path>*` — a sequence of children, such as a [`Vec`], iterable by reference
+///     from both ends.
+/// - `<pattern>` — pattern that the nodes are matched against.
+/// - `<match-guard>` — optional match guard that further restricts the match.
+/// - `<mapping>` — expression the bound variables of the matching node are mapped
+///   into [`Some`] value with.
+///
+/// # Examples
+///
+/// ```
+/// # use el_macro::visit_matches;
+/// #
+/// struct Node { kind: Kind, lhs: Option<Box<Node>>, body: Vec<Node> }
+///
+/// enum Kind { Block, Add, Literal(i64) }
+///
+/// let leaf = |n| Node { kind: Kind::Literal(n), lhs: None, body: Vec::new() };
+/// let ast = Node {
+///     kind: Kind::Block,
+///     lhs: None,
+///     body: vec![
+///         Node { kind: Kind::Add, lhs: Some(Box::new(leaf(1))), body: vec![leaf(41)] },
+///         leaf(42),
+///     ],
+/// };
+///
+/// let first = visit_matches!(ast, [.lhs?, .body*], Node { kind: Kind::Literal(n), .. } => *n);
+/// assert_eq!(first, Some(1));
+///
+/// let large = visit_matches!(ast, [.lhs?, .body*], Node { kind: Kind::Literal(n), .. } if *n > 1 => *n);
+/// assert_eq!(large, Some(41));
+///
+/// let shallow = visit_matches!(ast, [], Node { kind: Kind::Literal(n), .. } => *n);
+/// assert_eq!(shallow, None);
+/// ```
+#[macro_export]
+macro_rules! visit_matches {
+
+    (@children $x: tt $s: ident $n: ident [$($o: tt)*] [$($a: tt)+] * $(, $($r: tt)*)?) => {
+        $crate::visit_matches!(@children $x $s $n [
+            for child in ::core::iter::IntoIterator::into_iter(&$n $($a)+).rev() {
+                $s.push(child);
+            }
+            $($o)*
+        ] [] $($($r)*)?)
+    };
+
+    (@children $x: tt $s: ident $n: ident [$($o: tt)*] [$($a: tt)+] ? $(, $($r: tt)*)?) => {
+        $crate::visit_matches!(@children $x $s $n [
+            if let ::core::option::Option::Some(child) = &$n $($a)+ {
+                $s.push(child);
+            }
+            $($o)*
+        ] [] $($($r)*)?)
+    };
+
+    (@children $x: tt $s: ident $n: ident [$($o: tt)*] [$($a: tt)+] $(, $($r: tt)*)?) => {
+        $crate::visit_matches!(@children $x $s $n [
+            $s.push(&$n $($a)+);
+            $($o)*
+        ] [] $($($r)*)?)
+    };
+
+    (@children [$e: expr, $p: pat $(if $c: expr)? => $m: expr] $s: ident $n: ident [$($o: tt)*] []) => {
+        'visit: {
+            let mut $s = ::std::vec![&$e];
+            while let ::core::option::Option::Some($n) = $s.pop() {
+                match $n {
+                    $p $(if $c)? => break 'visit ::core::option::Option::Some($m),
+                    _ => { },
+                }
+                $($o)*
+            }
+            ::core::option::Option::None
+        }
+    };
+
+    (@children $x: tt $s: ident $n: ident [$($o: tt)*] [$($a: tt)*] $t: tt $($r: tt)*) => {
+        $crate::visit_matches!(@children $x $s $n [$($o)*] [$($a)* $t] $($r)*)
+    };
+
+    ($e: expr, [$($a: tt)*], $p: pat $(if $c: expr)? => $m: expr) => {
+        $crate::visit_matches!(@children [$e, $p $(if $c)? => $m] stack node [] [] $($a)*)
+    };
+
+}
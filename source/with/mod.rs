@@ -0,0 +1,87 @@
+//! The [`crate::with!`] macro
+
+
+/// Binds the unwrapped value for the duration of the body only
+///
+/// Works like the [`crate::bind!`] macro, but the variable binding is only visible
+/// within the provided body and the bound value is dropped as soon as the body is evaluated,
+/// rather than at the end of the enclosing scope. Yields the value of the body.
+///
+/// Useful for values that hold a resource, such as lock guards, that must not outlive
+/// the code that uses them.
+///
+/// # Syntax
+///
+/// ```text
+/// with!([mut] <var-name> [= <value-expr>], or [<err-handler>,] <flow-ctl>, <body>)
+/// ```
+///
+/// - `mut`, `<var-name>`, `<value-expr>`, `<err-handler>`, `<flow-ctl>` — same as for
+///   the [`crate::bind!`] macro.
+/// - `<body>` — block evaluated with the variable `<var-name>` bound to the unwrapped value.
+///
+/// # Examples
+///
+/// Basic usage:
+#[cfg_attr(feature = "impl-mutex", doc = "```")]
+#[cfg_attr(not(feature = "impl-mutex"), doc = "```ignore")]
+/// # use el_macro::with;
+/// # use std::sync::Mutex;
+/// #
+/// let counter = Mutex::new(41);
+///
+/// let x = with!(mut guard = &counter, or return, {
+///     *guard += 1;
+///     *guard
+/// });
+/// assert_eq!(x, 42);
+///
+/// // the guard is already released
+/// assert!(counter.try_lock().is_ok());
+/// ```
+///
+/// Handling error values:
+#[cfg_attr(feature = "impl-result", doc = "```")]
+#[cfg_attr(not(feature = "impl-result"), doc = "```ignore")]
+/// # use el_macro::with;
+/// #
+/// let handle_error = |err: &str| eprintln!("{err}!");
+///
+/// // prints 'error!' and returns
+/// with!(x = None::<i32>.ok_or("error"), or handle_error, return, {
+///     unreachable!();
+/// });
+/// unreachable!();
+/// ```
+#[macro_export]
+macro_rules! with {
+
+    ($n: ident $(= $e: expr)?, or $h: expr, $f: expr, $b: block) => {
+        {
+            $crate::bind!($n $(= $e)?, or $h, $f);
+            $b
+        }
+    };
+
+    ($n: ident $(= $e: expr)?, or $f: expr, $b: block) => {
+        {
+            $crate::bind!($n $(= $e)?, or $f);
+            $b
+        }
+    };
+
+    (mut $n: ident $(= $e: expr)?, or $h: expr, $f: expr, $b: block) => {
+        {
+            $crate::bind!(mut $n $(= $e)?, or $h, $f);
+            $b
+        }
+    };
+
+    (mut $n: ident $(= $e: expr)?, or $f: expr, $b: block) => {
+        {
+            $crate::bind!(mut $n $(= $e)?, or $f);
+            $b
+        }
+    };
+
+}